@@ -0,0 +1,189 @@
+use std::path::PathBuf;
+
+use tetris_core::tetromino::Tetromino;
+use tetris_core::{FixedTimestep, Recording, Tetris, TetrisState};
+use tetris_render::engine::{
+    input::KeyboardKey,
+    shapes::Vector2,
+    vectors::{Background, Color, FPSGraphic, Renderer},
+    Raylib, RaylibBuilder,
+};
+use tetris_render::InterpolatedTetris;
+
+use super::player::Human;
+use super::viewer::Viewer;
+
+pub struct Game {
+    raylib: Raylib,
+    tetris: Tetris,
+    timestep: FixedTimestep,
+    /// The falling tetromino before the last tick, if it is still the same one.
+    previous: Option<Tetromino>,
+    recording: Recording,
+    /// Where the recording of each game is saved once it ends, if anywhere.
+    recording_path: Option<PathBuf>,
+    /// How many games have been started, counting the current one.
+    games: u32,
+}
+
+impl Game {
+    pub fn new() -> Self {
+        const WINDOW_WIDTH: u16 = 1256;
+        const WINDOW_HEIGHT: u16 = 1032;
+
+        let tetris = Tetris::new();
+
+        Game {
+            raylib: RaylibBuilder::new("Tetris in Rust", WINDOW_WIDTH, WINDOW_HEIGHT)
+                .vsync()
+                .build(),
+            recording: Recording::of(&tetris),
+            tetris,
+            timestep: FixedTimestep::new(),
+            previous: None,
+            recording_path: None,
+            games: 1,
+        }
+    }
+
+    /// Saves a recording of every game to `path` in the binary replay format. When `path` ends
+    /// with `.json` it gets the JSON debug format instead, and the replay is saved next to it
+    /// with a `.replay` extension, since the JSON can't be played back. Games after the first
+    /// are numbered, so `game.replay` is followed by `game-2.replay`.
+    pub fn record_to(mut self, path: impl Into<PathBuf>) -> Self {
+        self.recording_path = Some(path.into());
+        self
+    }
+
+    pub fn start(mut self) {
+        let mut player = Human {
+            input: &self.raylib.input,
+        };
+
+        while !self.raylib.window.should_close() {
+            let action = player
+                .next()
+                .expect("Should be safe because [player.next] will never return [None]");
+
+            if self.raylib.input.key_pressed(KeyboardKey::KEY_P) {
+                self.tetris = self.tetris.toggle_pause();
+            }
+
+            if let TetrisState::GameOver(_) = self.tetris.state() {
+                if self.raylib.input.key_pressed(KeyboardKey::KEY_ENTER) {
+                    self.tetris = Tetris::new();
+                    self.recording = Recording::of(&self.tetris);
+                    self.previous = None;
+                    self.games += 1;
+                }
+            }
+
+            let ticks = self
+                .timestep
+                .advance(self.raylib.window.frame_time(), action);
+
+            // Pausing isn't part of a recording, so no ticks happen at all while paused, nor once
+            // the game is over.
+            if let TetrisState::Playing
+            | TetrisState::Ready { .. }
+            | TetrisState::LineClearDelay { .. } = self.tetris.state()
+            {
+                for moves in ticks {
+                    let falling_tetromino = self.tetris.falling_tetromino().clone();
+
+                    self.recording.record(&moves);
+                    self.tetris = self.tetris.tick(moves);
+
+                    // Spawns, holds and locks all come with an event, and leave the falling
+                    // tetromino somewhere unrelated to where it was.
+                    self.previous = match self.tetris.events().is_empty() {
+                        true => Some(falling_tetromino),
+                        false => None,
+                    };
+
+                    if let TetrisState::GameOver(_) = self.tetris.state() {
+                        self.save_recording();
+                        break;
+                    }
+                }
+            }
+
+            self.raylib
+                .window
+                .canvas()
+                .draw(&Background {
+                    color: Color::RAY_WHITE,
+                })
+                .draw(&FPSGraphic {
+                    position: Vector2 { x: 10.0, y: 10.0 },
+                })
+                .draw(&InterpolatedTetris {
+                    tetris: &self.tetris,
+                    previous: self.previous.as_ref(),
+                    alpha: self.timestep.alpha(),
+                })
+                .draw(&self.tetris.state());
+        }
+
+        // A finished game was already saved when it ended.
+        if !matches!(self.tetris.state(), TetrisState::GameOver(_)) {
+            self.save_recording();
+        }
+    }
+
+    /// Opens `recording` in the replay viewer, see [Viewer].
+    pub fn replay(self, recording: Recording) {
+        let mut viewer = Viewer::new(recording);
+
+        while !self.raylib.window.should_close() {
+            viewer.update(&self.raylib.input, self.raylib.window.frame_time());
+
+            self.raylib
+                .window
+                .canvas()
+                .draw(&Background {
+                    color: Color::RAY_WHITE,
+                })
+                .draw(&FPSGraphic {
+                    position: Vector2 { x: 10.0, y: 10.0 },
+                })
+                .draw(&viewer);
+        }
+    }
+
+    /// Where the current game is saved: `recording_path` itself for the first game, and
+    /// numbered after it for every game since.
+    fn recording_path(&self) -> Option<PathBuf> {
+        let path = self.recording_path.as_ref()?;
+
+        if self.games == 1 {
+            return Some(path.clone());
+        }
+
+        let mut file_name = path.file_stem().unwrap_or_default().to_os_string();
+        file_name.push(format!("-{}", self.games));
+
+        if let Some(extension) = path.extension() {
+            file_name.push(".");
+            file_name.push(extension);
+        }
+
+        Some(path.with_file_name(file_name))
+    }
+
+    fn save_recording(&self) {
+        if let Some(path) = self.recording_path() {
+            match path
+                .extension()
+                .is_some_and(|extension| extension == "json")
+            {
+                true => self
+                    .recording
+                    .write_json(&path)
+                    .and_then(|_| self.recording.write(path.with_extension("replay"))),
+                false => self.recording.write(path),
+            }
+            .expect("Should be able to save the recording to [recording_path]");
+        }
+    }
+}
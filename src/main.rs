@@ -1,9 +1,52 @@
-#[allow(dead_code)]
-#[allow(unused)]
-mod tetris;
-use tetris::Game;
+mod game;
+mod player;
+mod terminal;
+mod viewer;
+
+use std::env;
+use std::time::Duration;
+
+use game::Game;
+use terminal::TerminalGame;
+use tetris_core::Recording;
 
 fn main() {
-    let tetris = Game::new();
+    let arguments: Vec<String> = env::args().collect();
+
+    let value_of = |flag: &str| {
+        arguments
+            .iter()
+            .position(|argument| argument == flag)
+            .and_then(|index| arguments.get(index + 1))
+    };
+
+    if arguments.iter().any(|argument| argument == "--terminal") {
+        let terminal = match value_of("--repeat-delay") {
+            Some(milliseconds) => TerminalGame::new().repeat_delay(Duration::from_millis(
+                milliseconds
+                    .parse()
+                    .expect("Should be able to read [--repeat-delay] as milliseconds"),
+            )),
+            None => TerminalGame::new(),
+        };
+
+        terminal
+            .start()
+            .expect("Should be able to play in a terminal connected to stdin");
+        return;
+    }
+
+    if let Some(path) = value_of("--replay") {
+        let recording = Recording::read(path).expect("Should be able to read the replay at [path]");
+
+        Game::new().replay(recording);
+        return;
+    }
+
+    let tetris = match value_of("--record") {
+        Some(path) => Game::new().record_to(path),
+        None => Game::new(),
+    };
+
     tetris.start();
 }
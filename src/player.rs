@@ -1,15 +1,5 @@
-use std::process::exit;
-
-use super::engine::input::{Input, KeyboardKey};
-use super::tetromino::{Rotation, Step};
-
-#[derive(Clone)]
-pub enum TetrisMove {
-    Rotate(Rotation),
-    HardDrop,
-    SoftDrop,
-    Shift(Step),
-}
+use tetris_core::{Moves, Rotation, Step, TetrisMove};
+use tetris_render::engine::input::{Input, KeyboardKey};
 
 pub(super) struct Human<'a> {
     pub(super) input: &'a Input,
@@ -21,8 +11,6 @@ pub(super) struct Human<'a> {
 //     }
 // }
 
-pub(super) type Moves = Vec<TetrisMove>;
-
 impl Iterator for Human<'_> {
     type Item = Moves;
 
@@ -43,6 +31,7 @@ impl Iterator for Human<'_> {
                 (&key_pressed, KEY_LEFT_CONTROL, Rotate(Counterclockwise)),
                 (&key_down, KEY_DOWN, SoftDrop),
                 (&key_pressed, KEY_SPACE, HardDrop),
+                (&key_pressed, KEY_C, Hold),
             ]
             .iter()
             .filter_map(|(reader, key, action)| {
@@ -56,6 +45,3 @@ impl Iterator for Human<'_> {
         )
     }
 }
-
-pub(super) trait Player: Iterator<Item = TetrisMove> {}
-impl<T: Iterator<Item = TetrisMove>> Player for T {}
@@ -0,0 +1,506 @@
+use std::collections::HashMap;
+use std::fmt::Write as _;
+use std::io::{self, Read, Write};
+use std::thread;
+use std::time::{Duration, Instant};
+
+use tetris_core::matrix::{Cell, PLAYFIELD_COLUMNS, PLAYFIELD_ROWS};
+use tetris_core::tetromino::Position;
+use tetris_core::{FixedTimestep, Moves, Rotation, Step, TetrisMove, TetrominoKind, TICK};
+use tetris_core::{Settings, Tetris, TetrisState};
+use tetris_render::engine::vectors::Color;
+use tetris_render::TetrominoColor;
+
+/// Plays in the terminal instead of a raylib window, for machines without a display.
+pub struct TerminalGame {
+    tetris: Tetris,
+    repeat_delay: Duration,
+}
+
+impl TerminalGame {
+    pub fn new() -> Self {
+        TerminalGame {
+            tetris: Tetris::new(),
+            repeat_delay: Keyboard::DEFAULT_REPEAT_DELAY,
+        }
+    }
+
+    /// How long the terminal waits before it starts repeating a held key, see [Keyboard].
+    pub fn repeat_delay(mut self, repeat_delay: Duration) -> Self {
+        self.repeat_delay = repeat_delay;
+        self
+    }
+
+    pub fn start(mut self) -> io::Result<()> {
+        // A tapped key looks held until the repeat delay runs out, so auto shift has to wait
+        // for longer than that to tell a tap from a hold.
+        let settings = Settings {
+            delayed_auto_shift: (self.repeat_delay + Keyboard::REPEAT_TIMEOUT)
+                .max(Settings::default().delayed_auto_shift),
+            ..Settings::default()
+        };
+
+        self.tetris = Tetris::with_settings(settings.clone());
+
+        let _terminal = RawTerminal::enable()?;
+
+        let mut keyboard = Keyboard::new(self.repeat_delay);
+        let mut timestep = FixedTimestep::new();
+        let mut stdout = io::stdout();
+        let mut last_frame = Instant::now();
+
+        loop {
+            keyboard.poll()?;
+
+            if keyboard.key_pressed(Key::Interrupt) || keyboard.key_pressed(Key::Character('q')) {
+                return Ok(());
+            }
+
+            let action = keyboard
+                .next()
+                .expect("Should be safe because [keyboard.next] will never return [None]");
+
+            if keyboard.key_pressed(Key::Character('p')) {
+                self.tetris = self.tetris.toggle_pause();
+            }
+
+            if let TetrisState::GameOver(_) = self.tetris.state() {
+                if keyboard.key_pressed(Key::Enter) {
+                    self.tetris = Tetris::with_settings(settings.clone());
+                }
+            }
+
+            let now = Instant::now();
+
+            for moves in timestep.advance(now - last_frame, action) {
+                self.tetris = self.tetris.tick(moves);
+            }
+
+            last_frame = now;
+
+            stdout.write_all(render(&self.tetris).as_bytes())?;
+            stdout.flush()?;
+
+            thread::sleep(TICK.saturating_sub(now.elapsed()));
+        }
+    }
+}
+
+/// Puts the terminal in raw, non-blocking mode on the alternate screen with the cursor hidden,
+/// and puts everything back when dropped.
+struct RawTerminal {
+    original: libc::termios,
+}
+
+impl RawTerminal {
+    fn enable() -> io::Result<Self> {
+        let mut original = unsafe { std::mem::zeroed::<libc::termios>() };
+
+        if unsafe { libc::tcgetattr(libc::STDIN_FILENO, &mut original) } != 0 {
+            return Err(io::Error::last_os_error());
+        }
+
+        let mut raw = original;
+        raw.c_lflag &= !(libc::ICANON | libc::ECHO | libc::ISIG | libc::IEXTEN);
+        raw.c_iflag &= !(libc::IXON | libc::ICRNL);
+        raw.c_cc[libc::VMIN] = 0;
+        raw.c_cc[libc::VTIME] = 0;
+
+        if unsafe { libc::tcsetattr(libc::STDIN_FILENO, libc::TCSANOW, &raw) } != 0 {
+            return Err(io::Error::last_os_error());
+        }
+
+        let mut stdout = io::stdout();
+        stdout.write_all(b"\x1b[?1049h\x1b[?25l\x1b[2J")?;
+        stdout.flush()?;
+
+        Ok(RawTerminal { original })
+    }
+}
+
+impl Drop for RawTerminal {
+    fn drop(&mut self) {
+        let mut stdout = io::stdout();
+        let _ = stdout.write_all(b"\x1b[0m\x1b[?25h\x1b[?1049l");
+        let _ = stdout.flush();
+
+        unsafe { libc::tcsetattr(libc::STDIN_FILENO, libc::TCSANOW, &self.original) };
+    }
+}
+
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash)]
+pub(super) enum Key {
+    Up,
+    Down,
+    Left,
+    Right,
+    Enter,
+    /// Ctrl+C, which raw mode delivers as a byte instead of a signal.
+    Interrupt,
+    Character(char),
+}
+
+/// Reads keys from stdin. Terminals report presses and auto repeats but never releases, so a
+/// key counts as held down until it misses a repeat: for the repeat delay after it is pressed,
+/// then for [Keyboard::REPEAT_TIMEOUT] after every repeat.
+pub(super) struct Keyboard {
+    /// When each key was pressed and when it was last seen, repeats included.
+    seen: HashMap<Key, (Instant, Instant)>,
+    pressed: Vec<Key>,
+    repeat_delay: Duration,
+}
+
+impl Keyboard {
+    /// Longer than the delay before the first repeat on most systems, which is usually
+    /// between 250 and 600 milliseconds.
+    pub(super) const DEFAULT_REPEAT_DELAY: Duration = Duration::from_millis(650);
+    /// Longer than the time between two repeats.
+    const REPEAT_TIMEOUT: Duration = Duration::from_millis(100);
+
+    pub(super) fn new(repeat_delay: Duration) -> Self {
+        Keyboard {
+            seen: HashMap::new(),
+            pressed: Vec::new(),
+            repeat_delay,
+        }
+    }
+
+    /// Reads every key that arrived since the last poll.
+    pub(super) fn poll(&mut self) -> io::Result<()> {
+        let mut bytes = Vec::new();
+        let mut buffer = [0; 64];
+
+        loop {
+            match io::stdin().lock().read(&mut buffer)? {
+                0 => break,
+                read => bytes.extend_from_slice(&buffer[..read]),
+            }
+        }
+
+        let now = Instant::now();
+        let keys = parse_keys(&bytes);
+
+        self.pressed = keys
+            .iter()
+            .copied()
+            .filter(|&key| !self.key_down(key))
+            .collect();
+
+        for key in keys {
+            let pressed = match self.pressed.contains(&key) {
+                true => now,
+                false => self.seen.get(&key).map_or(now, |&(pressed, _)| pressed),
+            };
+
+            self.seen.insert(key, (pressed, now));
+        }
+
+        Ok(())
+    }
+
+    pub(super) fn key_down(&self, key: Key) -> bool {
+        self.seen.get(&key).is_some_and(|&(pressed, seen)| {
+            let timeout = match seen == pressed {
+                true => self.repeat_delay,
+                false => Keyboard::REPEAT_TIMEOUT,
+            };
+
+            seen.elapsed() < timeout
+        })
+    }
+
+    /// Whether `key` went down during the last poll.
+    pub(super) fn key_pressed(&self, key: Key) -> bool {
+        self.pressed.contains(&key)
+    }
+}
+
+fn parse_keys(mut bytes: &[u8]) -> Vec<Key> {
+    const ESCAPE: u8 = 0x1B;
+    const END_OF_TEXT: u8 = 0x03;
+
+    let mut keys = Vec::new();
+
+    while !bytes.is_empty() {
+        let (key, length) = match bytes {
+            [ESCAPE, b'[' | b'O', b'A', ..] => (Some(Key::Up), 3),
+            [ESCAPE, b'[' | b'O', b'B', ..] => (Some(Key::Down), 3),
+            [ESCAPE, b'[' | b'O', b'C', ..] => (Some(Key::Right), 3),
+            [ESCAPE, b'[' | b'O', b'D', ..] => (Some(Key::Left), 3),
+            [b'\r' | b'\n', ..] => (Some(Key::Enter), 1),
+            [END_OF_TEXT, ..] => (Some(Key::Interrupt), 1),
+            [byte, ..] if byte.is_ascii_graphic() || *byte == b' ' => {
+                (Some(Key::Character(byte.to_ascii_lowercase().into())), 1)
+            }
+            _ => (None, 1),
+        };
+
+        keys.extend(key);
+        bytes = &bytes[length..];
+    }
+
+    keys
+}
+
+impl Iterator for Keyboard {
+    type Item = Moves;
+
+    fn next(&mut self) -> Option<Self::Item> {
+        use Key::*;
+        use Rotation::*;
+        use TetrisMove::*;
+
+        let key_down: Box<dyn Fn(Key) -> bool> = Box::new(|key| self.key_down(key));
+        let key_pressed: Box<dyn Fn(Key) -> bool> = Box::new(|key| self.key_pressed(key));
+
+        Some(
+            [
+                (&key_down, Right, Shift(Step::Right)),
+                (&key_down, Left, Shift(Step::Left)),
+                (&key_pressed, Up, Rotate(Clockwise)),
+                (&key_pressed, Character('x'), Rotate(Clockwise)),
+                (&key_pressed, Character('z'), Rotate(Counterclockwise)),
+                (&key_down, Down, SoftDrop),
+                (&key_pressed, Character(' '), HardDrop),
+                (&key_pressed, Character('c'), Hold),
+            ]
+            .iter()
+            .filter_map(|(reader, key, action)| {
+                if reader(*key) {
+                    Some(action.clone())
+                } else {
+                    None
+                }
+            })
+            .collect(),
+        )
+    }
+}
+
+/// The frame as a grid of pixels, one for every matrix cell. Each character of the terminal shows
+/// two pixels stacked on top of each other with a half block, and since a character is about
+/// twice as tall as it is wide, a cell comes out square.
+struct Screen {
+    pixels: [[Option<Color>; Screen::WIDTH]; Screen::HEIGHT],
+}
+
+impl Screen {
+    const HOLD_COLUMN: usize = 0;
+    const PLAYFIELD_COLUMN: usize = 7;
+    const NEXT_COLUMN: usize = 18;
+    const BOX_COLUMNS: usize = 6;
+
+    const WIDTH: usize = Screen::NEXT_COLUMN + Screen::BOX_COLUMNS;
+    const HEIGHT: usize = PLAYFIELD_ROWS;
+
+    fn new() -> Self {
+        Screen {
+            pixels: [[None; Screen::WIDTH]; Screen::HEIGHT],
+        }
+    }
+
+    /// Paints the cell `row` rows down and `column` columns across, blending translucent colors
+    /// over what is already there.
+    fn paint(&mut self, row: usize, column: usize, color: Color) {
+        let Some(pixel) = self
+            .pixels
+            .get_mut(row)
+            .and_then(|line| line.get_mut(column))
+        else {
+            return;
+        };
+
+        *pixel = Some(match *pixel {
+            Some(below) => blend(below, color),
+            None => color,
+        });
+    }
+
+    fn fill(&mut self, row: usize, column: usize, rows: usize, columns: usize, color: Color) {
+        for row in row..(row + rows) {
+            for column in column..(column + columns) {
+                self.paint(row, column, color);
+            }
+        }
+    }
+
+    /// Paints `kind` in its spawn orientation with its top left corner at `row` and `column`.
+    fn paint_preview(&mut self, row: usize, column: usize, kind: TetrominoKind, color: Color) {
+        let minoes = kind.new().minoes();
+
+        let top = minoes.iter().map(|mino| mino.row).max().unwrap_or_default();
+        let left = minoes
+            .iter()
+            .map(|mino| mino.column)
+            .min()
+            .unwrap_or_default();
+
+        for Position {
+            row: mino_row,
+            column: mino_column,
+        } in minoes
+        {
+            self.paint(
+                row + (top - mino_row) as usize,
+                column + (mino_column - left) as usize,
+                color,
+            );
+        }
+    }
+
+    /// Paints a cell of the matrix, skipping the ones hidden in the vanish zone.
+    fn paint_matrix(&mut self, Position { row, column }: Position, color: Color) {
+        if (0..PLAYFIELD_ROWS as i8).contains(&row) {
+            self.paint(
+                PLAYFIELD_ROWS - 1 - row as usize,
+                Screen::PLAYFIELD_COLUMN + column as usize,
+                color,
+            );
+        }
+    }
+
+    fn render(&self, output: &mut String) {
+        for rows in self.pixels.chunks(2) {
+            for column in 0..Screen::WIDTH {
+                let top = rows[0][column];
+                let bottom = rows.get(1).and_then(|row| row[column]);
+
+                let _ = match (top, bottom) {
+                    (None, None) => write!(output, "\x1b[0m "),
+                    (Some(top), None) => write!(output, "\x1b[0m{}\u{2580}", foreground(top)),
+                    (None, Some(bottom)) => write!(output, "\x1b[0m{}\u{2584}", foreground(bottom)),
+                    (Some(top), Some(bottom)) => {
+                        write!(output, "{}{}\u{2580}", foreground(top), background(bottom))
+                    }
+                };
+            }
+
+            output.push_str("\x1b[0m\x1b[K\r\n");
+        }
+    }
+}
+
+fn foreground(color: Color) -> String {
+    format!("\x1b[38;2;{};{};{}m", color.red, color.green, color.blue)
+}
+
+fn background(color: Color) -> String {
+    format!("\x1b[48;2;{};{};{}m", color.red, color.green, color.blue)
+}
+
+/// Composites `color` over the opaque `below`, since terminals have no notion of transparency.
+fn blend(below: Color, color: Color) -> Color {
+    let alpha = f32::from(color.alpha) / f32::from(u8::MAX);
+    let channel =
+        |below: u8, above: u8| (f32::from(below) * (1.0 - alpha) + f32::from(above) * alpha) as u8;
+
+    Color::new(
+        channel(below.red, color.red),
+        channel(below.green, color.green),
+        channel(below.blue, color.blue),
+    )
+}
+
+/// Draws the whole game as a string of ANSI escape codes that redraws the terminal in place.
+fn render(tetris: &Tetris) -> String {
+    const PREVIEW_ROWS: usize = 3;
+    const CONTROLS: &str =
+        "Left/Right shift  Up/X rotate  Z rotate left  Down soft drop  Space hard drop  C hold  P pause  Q quit";
+
+    let mut screen = Screen::new();
+
+    screen.fill(
+        0,
+        Screen::HOLD_COLUMN,
+        4,
+        Screen::BOX_COLUMNS,
+        Color::DARK_GRAY,
+    );
+
+    if let Some(held) = tetris.hold_queue().held() {
+        let color = match tetris.hold_queue().can_hold() {
+            true => held.color(),
+            false => Color::GRAY,
+        };
+
+        screen.paint_preview(1, Screen::HOLD_COLUMN + 1, held, color);
+    }
+
+    screen.fill(
+        0,
+        Screen::PLAYFIELD_COLUMN,
+        PLAYFIELD_ROWS,
+        PLAYFIELD_COLUMNS,
+        Color::BLACK,
+    );
+
+    for row in 0..PLAYFIELD_ROWS {
+        for column in 0..PLAYFIELD_COLUMNS {
+            let position = Position {
+                row: row as i8,
+                column: column as i8,
+            };
+
+            let color = match tetris.matrix().cell(&position) {
+                Some(Cell::Filled(kind)) => kind.color(),
+                Some(Cell::Garbage) => Color::GRAY,
+                Some(Cell::Empty) | None => continue,
+            };
+
+            screen.paint_matrix(position, color);
+        }
+    }
+
+    if let TetrisState::Playing | TetrisState::Paused = tetris.state() {
+        let falling_tetromino = tetris.falling_tetromino();
+
+        if let Some(ghost) = tetris.ghost_tetromino() {
+            for position in ghost.minoes() {
+                screen.paint_matrix(position, ghost.kind().color().fade(0.3));
+            }
+        }
+
+        for position in falling_tetromino.minoes() {
+            screen.paint_matrix(position, falling_tetromino.kind().color());
+        }
+    }
+
+    let upcoming = tetris.next_queue().upcoming().count();
+
+    screen.fill(
+        0,
+        Screen::NEXT_COLUMN,
+        PREVIEW_ROWS * upcoming + 1,
+        Screen::BOX_COLUMNS,
+        Color::DARK_GRAY,
+    );
+
+    for (index, kind) in tetris.next_queue().upcoming().enumerate() {
+        screen.paint_preview(
+            1 + PREVIEW_ROWS * index,
+            Screen::NEXT_COLUMN + 1,
+            *kind,
+            kind.color(),
+        );
+    }
+
+    let mut output = String::from("\x1b[H");
+
+    screen.render(&mut output);
+
+    let score = tetris.score();
+
+    let _ = write!(
+        output,
+        "\r\nSCORE {}  LEVEL {}  LINES {}\x1b[K\r\n{}\x1b[K\r\n{}\x1b[K\r\n",
+        score.points(),
+        score.level(),
+        score.lines(),
+        tetris.state().message().join(" - "),
+        CONTROLS,
+    );
+
+    output.push_str("\x1b[J");
+
+    output
+}
@@ -1,3 +0,0 @@
-pub mod positions;
-pub mod shapes;
-pub mod vectors;
@@ -1,4 +0,0 @@
-struct Vector2 {
-    x: f32,
-    y: f32,
-}
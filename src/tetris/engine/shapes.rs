@@ -1,3 +0,0 @@
-struct Rectangle {
-    width: 
-}
\ No newline at end of file
@@ -0,0 +1,196 @@
+use std::time::Duration;
+
+use tetris_core::tetromino::Tetromino;
+use tetris_core::{FixedTimestep, Playback, Recording};
+use tetris_render::engine::{
+    input::{Input, KeyboardKey},
+    shapes::Vector2,
+    vectors::{Color, Drawable, Renderer, TextGraphic},
+};
+use tetris_render::{InterpolatedTetris, MovesGraphic};
+
+const SPEEDS: [f32; 6] = [0.25, 0.5, 1.0, 2.0, 4.0, 8.0];
+const NORMAL_SPEED: usize = 2;
+
+const DIGIT_KEYS: [(KeyboardKey, char); 10] = [
+    (KeyboardKey::KEY_ZERO, '0'),
+    (KeyboardKey::KEY_ONE, '1'),
+    (KeyboardKey::KEY_TWO, '2'),
+    (KeyboardKey::KEY_THREE, '3'),
+    (KeyboardKey::KEY_FOUR, '4'),
+    (KeyboardKey::KEY_FIVE, '5'),
+    (KeyboardKey::KEY_SIX, '6'),
+    (KeyboardKey::KEY_SEVEN, '7'),
+    (KeyboardKey::KEY_EIGHT, '8'),
+    (KeyboardKey::KEY_NINE, '9'),
+];
+
+/// Watches a recorded game with the moves of every tick shown next to it.
+///
+/// Space or P pauses, up and down change the speed, and while paused right and left step a
+/// single tick forward or back. The brackets jump to the previous or next piece, typing a
+/// piece number followed by Enter jumps straight to it, and Home goes back to the start.
+pub(super) struct Viewer {
+    playback: Playback,
+    timestep: FixedTimestep,
+    /// The falling tetromino before the last tick, if it is still the same one.
+    previous: Option<Tetromino>,
+    paused: bool,
+    speed: usize,
+    /// The piece number being typed in.
+    piece: String,
+}
+
+impl Viewer {
+    pub(super) fn new(recording: Recording) -> Self {
+        Viewer {
+            playback: Playback::new(recording),
+            timestep: FixedTimestep::new(),
+            previous: None,
+            paused: false,
+            speed: NORMAL_SPEED,
+            piece: String::new(),
+        }
+    }
+
+    pub(super) fn update(&mut self, input: &Input, frame_time: Duration) {
+        use KeyboardKey::*;
+
+        if input.key_pressed(KEY_SPACE) || input.key_pressed(KEY_P) {
+            self.paused = !self.paused;
+        }
+
+        if input.key_pressed(KEY_UP) {
+            self.speed = (self.speed + 1).min(SPEEDS.len() - 1);
+        }
+
+        if input.key_pressed(KEY_DOWN) {
+            self.speed = self.speed.saturating_sub(1);
+        }
+
+        for (key, digit) in DIGIT_KEYS {
+            if input.key_pressed(key) {
+                self.piece.push(digit);
+            }
+        }
+
+        if input.key_pressed(KEY_BACKSPACE) {
+            self.piece.pop();
+        }
+
+        if input.key_pressed(KEY_ENTER) {
+            if let Ok(piece) = self.piece.parse() {
+                self.seek_piece(piece);
+            }
+
+            self.piece.clear();
+        }
+
+        if input.key_pressed(KEY_LEFT_BRACKET) {
+            self.seek_piece(self.playback.piece().saturating_sub(1).max(1));
+        }
+
+        if input.key_pressed(KEY_RIGHT_BRACKET) {
+            self.seek_piece(self.playback.piece() + 1);
+        }
+
+        if input.key_pressed(KEY_HOME) {
+            self.playback.seek(0);
+            self.previous = None;
+        }
+
+        if self.paused {
+            self.timestep.advance(frame_time, Vec::new());
+
+            if input.key_pressed(KEY_RIGHT) {
+                self.step();
+            }
+
+            if input.key_pressed(KEY_LEFT) {
+                self.playback.seek(self.playback.tick().saturating_sub(1));
+                self.previous = None;
+            }
+
+            return;
+        }
+
+        for _ in self
+            .timestep
+            .advance(frame_time.mul_f32(SPEEDS[self.speed]), Vec::new())
+        {
+            self.step();
+        }
+    }
+
+    fn step(&mut self) {
+        let falling_tetromino = self.playback.tetris().falling_tetromino().clone();
+
+        if self.playback.step() {
+            self.previous = match self.playback.tetris().events().is_empty() {
+                true => Some(falling_tetromino),
+                false => None,
+            };
+        }
+    }
+
+    fn seek_piece(&mut self, piece: u32) {
+        self.playback.seek_piece(piece);
+        self.previous = None;
+    }
+}
+
+impl<'a> Drawable<'a> for Viewer {
+    fn draw<C: Renderer>(&self, canvas: C) -> C {
+        const STATUS_POSITION: Vector2 = Vector2 { x: 883.0, y: 760.0 };
+        const LINE_HEIGHT: f32 = 40.0;
+
+        let canvas = canvas
+            .draw(&InterpolatedTetris {
+                tetris: self.playback.tetris(),
+                previous: self.previous.as_ref(),
+                alpha: match self.paused {
+                    true => 1.0,
+                    false => self.timestep.alpha(),
+                },
+            })
+            .draw(&MovesGraphic {
+                moves: self.playback.moves(),
+            });
+
+        let state = match (self.paused, self.playback.finished()) {
+            (_, true) => "END".to_string(),
+            (true, false) => "PAUSED".to_string(),
+            (false, false) => format!("SPEED {}x", SPEEDS[self.speed]),
+        };
+
+        let status = [
+            format!("PIECE {}", self.playback.piece()),
+            format!(
+                "TICK {} / {}",
+                self.playback.tick(),
+                self.playback.recording().ticks()
+            ),
+            state,
+            match self.piece.is_empty() {
+                true => String::new(),
+                false => format!("GO TO {}_", self.piece),
+            },
+        ];
+
+        status
+            .iter()
+            .enumerate()
+            .fold(canvas, |canvas, (index, line)| {
+                canvas.draw(&TextGraphic {
+                    text: line,
+                    position: STATUS_POSITION
+                        + Vector2 {
+                            x: 0.0,
+                            y: LINE_HEIGHT * (index as f32),
+                        },
+                    font_size: 30.0,
+                    color: Color::BLACK,
+                })
+            })
+    }
+}
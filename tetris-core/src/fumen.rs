@@ -0,0 +1,692 @@
+use std::error::Error;
+use std::fmt::{self, Display, Formatter};
+
+use super::matrix::{Cell, Matrix, MATRIX_ROWS, PLAYFIELD_COLUMNS};
+use super::tetris::Tetris;
+use super::tetromino::{Offset, Position, RotationState, Tetromino, TetrominoKind};
+
+const PREFIXES: [&str; 3] = ["v115@", "m115@", "d115@"];
+
+const ENCODE_TABLE: &[u8; 64] = b"ABCDEFGHIJKLMNOPQRSTUVWXYZabcdefghijklmnopqrstuvwxyz0123456789+/";
+const COMMENT_TABLE: &[u8; 95] = b" !\"#$%&'()*+,-./0123456789:;<=>?@ABCDEFGHIJKLMNOPQRSTUVWXYZ[\\]^_`abcdefghijklmnopqrstuvwxyz{|}~";
+
+/// Comment characters are packed four at a time in this base, one more than the size of
+/// [COMMENT_TABLE].
+const COMMENT_BASE: u32 = COMMENT_TABLE.len() as u32 + 1;
+const MAX_COMMENT_LENGTH: usize = 4095;
+
+/// The rows of a fumen field, not counting the garbage row below them.
+const FIELD_ROWS: usize = 23;
+const FIELD_BLOCKS: u32 = ((FIELD_ROWS + 1) * PLAYFIELD_COLUMNS) as u32;
+
+/// The largest number of pages in a row that can share one unchanged field.
+const MAX_REPEAT: u32 = ENCODE_TABLE.len() as u32 - 1;
+
+/// One page of a fumen: a field, the tetromino placed on it and a comment.
+#[derive(Debug, Clone)]
+pub struct Page {
+    /// The field before the tetromino is placed. Only the bottom 23 rows fit in a fumen.
+    pub matrix: Matrix,
+    /// The row below the field that [Page::rise] pushes up into it.
+    pub garbage: [Cell; PLAYFIELD_COLUMNS],
+    /// The tetromino placed on the field, if any.
+    pub tetromino: Option<Tetromino>,
+    /// Carried over from the previous page unless it is changed.
+    pub comment: String,
+    /// Whether the tetromino locks into the field, clearing lines, for the next page.
+    pub lock: bool,
+    /// Whether the garbage row rises into the field for the next page.
+    pub rise: bool,
+    /// Whether the field is mirrored for the next page.
+    pub mirror: bool,
+}
+
+impl Page {
+    /// A page showing just `matrix`, with no tetromino or comment.
+    pub fn new(matrix: Matrix) -> Self {
+        Page {
+            matrix,
+            garbage: [Cell::Empty; PLAYFIELD_COLUMNS],
+            tetromino: None,
+            comment: String::new(),
+            lock: true,
+            rise: false,
+            mirror: false,
+        }
+    }
+
+    /// The moment `tetris` is at, with its falling tetromino placed on its matrix.
+    pub fn of(tetris: &Tetris) -> Self {
+        Page {
+            tetromino: Some(tetris.falling_tetromino().clone()),
+            ..Page::new(tetris.matrix().clone())
+        }
+    }
+}
+
+/// Why a fumen couldn't be decoded or encoded.
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum FumenError {
+    /// The data doesn't start with `v115@`.
+    UnsupportedVersion,
+    /// A character that isn't one of fumen's 64 digits.
+    InvalidCharacter(char),
+    /// The data stops in the middle of a page.
+    UnexpectedEnd,
+    /// The data decodes to cells, tetrominoes or comments that can't exist.
+    InvalidData,
+    /// A cell or a tetromino lies above the 23 rows of a fumen field.
+    OutOfField,
+}
+
+impl Display for FumenError {
+    fn fmt(&self, formatter: &mut Formatter<'_>) -> fmt::Result {
+        match self {
+            FumenError::UnsupportedVersion => write!(formatter, "not a v115 fumen"),
+            FumenError::InvalidCharacter(character) => {
+                write!(formatter, "invalid character {character:?} in fumen")
+            }
+            FumenError::UnexpectedEnd => write!(formatter, "fumen ended unexpectedly"),
+            FumenError::InvalidData => write!(formatter, "invalid fumen data"),
+            FumenError::OutOfField => write!(formatter, "does not fit in a fumen field"),
+        }
+    }
+}
+
+impl Error for FumenError {}
+
+/// Reads every page of a v115 fumen, such as `v115@vhAAgH`.
+pub fn decode(fumen: &str) -> Result<Vec<Page>, FumenError> {
+    let data = PREFIXES
+        .iter()
+        .find_map(|prefix| fumen.strip_prefix(prefix))
+        .ok_or(FumenError::UnsupportedVersion)?;
+
+    let digits = data
+        .chars()
+        .filter(|&character| character != '?')
+        .map(|character| {
+            ENCODE_TABLE
+                .iter()
+                .position(|&digit| char::from(digit) == character)
+                .map(|digit| digit as u32)
+                .ok_or(FumenError::InvalidCharacter(character))
+        })
+        .collect::<Result<Vec<u32>, FumenError>>()?;
+
+    let mut reader = Reader { digits: &digits };
+
+    let mut pages = Vec::new();
+    let mut field = Field::empty();
+    let mut repeat = 0;
+    let mut comment = String::new();
+
+    while !reader.digits.is_empty() {
+        if repeat > 0 {
+            repeat -= 1;
+        } else {
+            let changed;
+            (field, changed) = field.decode(&mut reader)?;
+
+            if !changed {
+                repeat = reader.poll(1)?;
+            }
+        }
+
+        let mut action = reader.poll(3)?;
+        let mut take = |count: u32| {
+            let value = action % count;
+            action /= count;
+            value
+        };
+
+        let kind = take(8);
+        let rotation_state = take(4);
+        let coordinate = take(FIELD_BLOCKS);
+        let rise = take(2) == 1;
+        let mirror = take(2) == 1;
+        let _colorize = take(2);
+        let comment_changed = take(2) == 1;
+        let lock = take(2) == 0;
+
+        if comment_changed {
+            comment = decode_comment(&mut reader)?;
+        }
+
+        let tetromino = match kind {
+            0 => None,
+            kind => Some(decode_tetromino(
+                kind_from_code(kind)?,
+                rotation_from_code(rotation_state),
+                coordinate,
+            )),
+        };
+
+        let (matrix, garbage) = field.page();
+
+        let page = Page {
+            matrix,
+            garbage,
+            tetromino,
+            comment: comment.clone(),
+            lock,
+            rise,
+            mirror,
+        };
+
+        field = field.apply(&page);
+        pages.push(page);
+    }
+
+    Ok(pages)
+}
+
+/// Writes `pages` as a v115 fumen.
+pub fn encode(pages: &[Page]) -> Result<String, FumenError> {
+    let mut digits = Vec::new();
+
+    let mut previous = Field::empty();
+    let mut previous_comment = String::new();
+    let mut repeat_index: Option<usize> = None;
+
+    for (index, page) in pages.iter().enumerate() {
+        let field = Field::of(page)?;
+
+        match repeat_index {
+            _ if field != previous => {
+                field.encode(&previous, &mut digits);
+                repeat_index = None;
+            }
+            Some(repeat_index) if digits[repeat_index] < MAX_REPEAT => {
+                digits[repeat_index] += 1;
+            }
+            _ => {
+                field.encode(&previous, &mut digits);
+                digits.push(0);
+                repeat_index = Some(digits.len() - 1);
+            }
+        }
+
+        let (kind, rotation_state, coordinate) = match &page.tetromino {
+            Some(tetromino) => (
+                kind_code(tetromino.kind()),
+                rotation_code(tetromino.rotation_state()),
+                encode_coordinate(tetromino)?,
+            ),
+            None => (0, 0, 0),
+        };
+
+        let comment_changed = page.comment != previous_comment;
+
+        // Packed from the most significant field down, the reverse of the order they are
+        // decoded in.
+        let action = [
+            (2, u32::from(!page.lock)),
+            (2, u32::from(comment_changed)),
+            (2, u32::from(index == 0)),
+            (2, u32::from(page.mirror)),
+            (2, u32::from(page.rise)),
+            (FIELD_BLOCKS, coordinate),
+            (4, rotation_state),
+            (8, kind),
+        ]
+        .iter()
+        .fold(0, |action, &(count, value)| action * count + value);
+
+        push(&mut digits, action, 3);
+
+        if comment_changed {
+            encode_comment(&page.comment, &mut digits);
+            previous_comment = page.comment.clone();
+        }
+
+        previous = field.apply(page);
+    }
+
+    let data = digits
+        .iter()
+        .map(|&digit| char::from(ENCODE_TABLE[digit as usize]))
+        .collect::<Vec<char>>();
+
+    // Fumen breaks long data into lines with question marks, the first after 42 characters
+    // and then every 47.
+    let lines = match data.len() <= 42 {
+        true => vec![data.iter().collect::<String>()],
+        false => std::iter::once(&data[..42])
+            .chain(data[42..].chunks(47))
+            .map(|line| line.iter().collect::<String>())
+            .collect(),
+    };
+
+    Ok(format!("{}{}", PREFIXES[0], lines.join("?")))
+}
+
+/// A fumen field as piece codes, with the garbage row first and the top row last.
+#[derive(Clone, PartialEq, Eq)]
+struct Field {
+    rows: [[u32; PLAYFIELD_COLUMNS]; FIELD_ROWS + 1],
+}
+
+impl Field {
+    fn empty() -> Self {
+        Field {
+            rows: [[0; PLAYFIELD_COLUMNS]; FIELD_ROWS + 1],
+        }
+    }
+
+    fn of(page: &Page) -> Result<Self, FumenError> {
+        let mut field = Field::empty();
+
+        field.rows[0] = page.garbage.map(cell_code);
+
+        for row in 0..MATRIX_ROWS {
+            for column in 0..PLAYFIELD_COLUMNS {
+                let cell = page
+                    .matrix
+                    .cell(&Position {
+                        row: row as i8,
+                        column: column as i8,
+                    })
+                    .expect("Should be safe because the position is inside of the matrix");
+
+                match field.rows.get_mut(row + 1) {
+                    Some(line) => line[column] = cell_code(cell),
+                    None if cell != Cell::Empty => return Err(FumenError::OutOfField),
+                    None => {}
+                }
+            }
+        }
+
+        Ok(field)
+    }
+
+    fn page(&self) -> (Matrix, [Cell; PLAYFIELD_COLUMNS]) {
+        let matrix =
+            self.rows[1..]
+                .iter()
+                .enumerate()
+                .fold(Matrix::new(), |matrix, (row, line)| {
+                    line.iter()
+                        .enumerate()
+                        .fold(matrix, |matrix, (column, &code)| {
+                            matrix.with_cell(
+                                &Position {
+                                    row: row as i8,
+                                    column: column as i8,
+                                },
+                                cell_from_code(code),
+                            )
+                        })
+                });
+
+        (matrix, self.rows[0].map(cell_from_code))
+    }
+
+    /// The field for the page after `page`.
+    fn apply(mut self, page: &Page) -> Self {
+        if !page.lock {
+            return self;
+        }
+
+        if let Some(tetromino) = &page.tetromino {
+            for Position { row, column } in tetromino.minoes() {
+                if let Some(code) = usize::try_from(row + 1)
+                    .ok()
+                    .and_then(|row| self.rows.get_mut(row))
+                    .and_then(|line| line.get_mut(usize::try_from(column).ok()?))
+                {
+                    *code = kind_code(tetromino.kind());
+                }
+            }
+        }
+
+        let mut kept = self.rows[1..]
+            .iter()
+            .filter(|line| line.contains(&0))
+            .copied()
+            .collect::<Vec<[u32; PLAYFIELD_COLUMNS]>>();
+
+        kept.resize(FIELD_ROWS, [0; PLAYFIELD_COLUMNS]);
+        self.rows[1..].copy_from_slice(&kept);
+
+        if page.rise {
+            self.rows.copy_within(0..FIELD_ROWS, 1);
+            self.rows[0] = [0; PLAYFIELD_COLUMNS];
+        }
+
+        if page.mirror {
+            for line in &mut self.rows[1..] {
+                line.reverse();
+            }
+        }
+
+        self
+    }
+
+    /// The cells from the top left to the bottom right, garbage row last, the order fumen
+    /// stores them in.
+    fn blocks(&self) -> impl Iterator<Item = u32> + '_ {
+        self.rows.iter().rev().flatten().copied()
+    }
+
+    /// Stores the difference from `previous` as runs of equal differences.
+    fn encode(&self, previous: &Field, digits: &mut Vec<u32>) {
+        let differences = self
+            .blocks()
+            .zip(previous.blocks())
+            .map(|(current, previous)| current + 8 - previous)
+            .collect::<Vec<u32>>();
+
+        for run in differences.chunk_by(|left, right| left == right) {
+            push(digits, run[0] * FIELD_BLOCKS + (run.len() as u32 - 1), 2);
+        }
+    }
+
+    /// Reads the field that follows this one, and whether it is any different.
+    fn decode(&self, reader: &mut Reader) -> Result<(Self, bool), FumenError> {
+        let mut previous = self.blocks();
+        let mut blocks = Vec::with_capacity(FIELD_BLOCKS as usize);
+        let mut changed = true;
+
+        while blocks.len() < FIELD_BLOCKS as usize {
+            let run = reader.poll(2)?;
+            let difference = run / FIELD_BLOCKS;
+            let length = run % FIELD_BLOCKS + 1;
+
+            if difference == 8 && length == FIELD_BLOCKS {
+                changed = false;
+            }
+
+            for _ in 0..length {
+                let code = (previous.next().ok_or(FumenError::InvalidData)? + difference)
+                    .checked_sub(8)
+                    .filter(|&code| code <= 8)
+                    .ok_or(FumenError::InvalidData)?;
+
+                blocks.push(code);
+            }
+        }
+
+        let mut field = Field::empty();
+
+        for (line, blocks) in field
+            .rows
+            .iter_mut()
+            .rev()
+            .zip(blocks.chunks(PLAYFIELD_COLUMNS))
+        {
+            line.copy_from_slice(blocks);
+        }
+
+        Ok((field, changed))
+    }
+}
+
+struct Reader<'a> {
+    digits: &'a [u32],
+}
+
+impl Reader<'_> {
+    /// Reads a number stored as `count` base 64 digits, least significant first.
+    fn poll(&mut self, count: usize) -> Result<u32, FumenError> {
+        if self.digits.len() < count {
+            return Err(FumenError::UnexpectedEnd);
+        }
+
+        let (number, rest) = self.digits.split_at(count);
+        self.digits = rest;
+
+        Ok(number
+            .iter()
+            .rev()
+            .fold(0, |value, &digit| value * 64 + digit))
+    }
+}
+
+fn push(digits: &mut Vec<u32>, mut value: u32, count: usize) {
+    for _ in 0..count {
+        digits.push(value % 64);
+        value /= 64;
+    }
+}
+
+fn decode_comment(reader: &mut Reader) -> Result<String, FumenError> {
+    let length = reader.poll(2)? as usize;
+    let mut escaped = String::with_capacity(length);
+
+    while escaped.len() < length {
+        let mut value = reader.poll(5)?;
+
+        for _ in 0..(length - escaped.len()).min(4) {
+            let character = COMMENT_TABLE
+                .get((value % COMMENT_BASE) as usize)
+                .ok_or(FumenError::InvalidData)?;
+
+            escaped.push(char::from(*character));
+            value /= COMMENT_BASE;
+        }
+    }
+
+    unescape(&escaped)
+}
+
+fn encode_comment(comment: &str, digits: &mut Vec<u32>) {
+    let escaped = escape(comment);
+    let escaped = &escaped.as_bytes()[..escaped.len().min(MAX_COMMENT_LENGTH)];
+
+    push(digits, escaped.len() as u32, 2);
+
+    for characters in escaped.chunks(4) {
+        let value = characters.iter().rev().fold(0, |value, character| {
+            let index = COMMENT_TABLE
+                .iter()
+                .position(|entry| entry == character)
+                .expect("Should be safe because [escape] only leaves printable ASCII");
+
+            value * COMMENT_BASE + index as u32
+        });
+
+        push(digits, value, 5);
+    }
+}
+
+/// JavaScript's `escape`, which fumen runs comments through so that they only hold printable
+/// ASCII.
+fn escape(text: &str) -> String {
+    text.encode_utf16()
+        .map(|unit| match char::from_u32(u32::from(unit)) {
+            Some(character)
+                if character.is_ascii_alphanumeric() || "@*_+-./".contains(character) =>
+            {
+                character.to_string()
+            }
+            _ if unit < 0x100 => format!("%{unit:02X}"),
+            _ => format!("%u{unit:04X}"),
+        })
+        .collect()
+}
+
+/// JavaScript's `unescape`, undoing [escape].
+fn unescape(text: &str) -> Result<String, FumenError> {
+    let mut units = Vec::with_capacity(text.len());
+    let mut rest = text;
+
+    while let Some(character) = rest.chars().next() {
+        let (unit, length) = match (character, rest.get(1..2)) {
+            ('%', Some("u")) => (rest.get(2..6).and_then(hexadecimal), 6),
+            ('%', _) => (rest.get(1..3).and_then(hexadecimal), 3),
+            _ => (Some(character as u16), 1),
+        };
+
+        // Like `unescape`, a percent sign that doesn't start an escape is kept as it is.
+        match unit {
+            Some(unit) => {
+                units.push(unit);
+                rest = &rest[length..];
+            }
+            None => {
+                units.push(u16::from(b'%'));
+                rest = &rest[1..];
+            }
+        }
+    }
+
+    String::from_utf16(&units).map_err(|_| FumenError::InvalidData)
+}
+
+fn hexadecimal(digits: &str) -> Option<u16> {
+    match digits.chars().all(|digit| digit.is_ascii_hexdigit()) {
+        true => u16::from_str_radix(digits, 16).ok(),
+        false => None,
+    }
+}
+
+fn cell_code(cell: Cell) -> u32 {
+    match cell {
+        Cell::Empty => 0,
+        Cell::Filled(kind) => kind_code(kind),
+        Cell::Garbage => 8,
+    }
+}
+
+fn cell_from_code(code: u32) -> Cell {
+    match code {
+        0 => Cell::Empty,
+        8 => Cell::Garbage,
+        code => Cell::Filled(
+            kind_from_code(code).expect("Should be safe because [Field::decode] checks codes"),
+        ),
+    }
+}
+
+fn kind_code(kind: TetrominoKind) -> u32 {
+    match kind {
+        TetrominoKind::I => 1,
+        TetrominoKind::L => 2,
+        TetrominoKind::O => 3,
+        TetrominoKind::Z => 4,
+        TetrominoKind::T => 5,
+        TetrominoKind::J => 6,
+        TetrominoKind::S => 7,
+    }
+}
+
+fn kind_from_code(code: u32) -> Result<TetrominoKind, FumenError> {
+    Ok(match code {
+        1 => TetrominoKind::I,
+        2 => TetrominoKind::L,
+        3 => TetrominoKind::O,
+        4 => TetrominoKind::Z,
+        5 => TetrominoKind::T,
+        6 => TetrominoKind::J,
+        7 => TetrominoKind::S,
+        _ => return Err(FumenError::InvalidData),
+    })
+}
+
+fn rotation_code(rotation_state: RotationState) -> u32 {
+    match rotation_state {
+        RotationState::Reverse => 0,
+        RotationState::Right => 1,
+        RotationState::Spawn => 2,
+        RotationState::Left => 3,
+    }
+}
+
+fn rotation_from_code(code: u32) -> RotationState {
+    match code {
+        0 => RotationState::Reverse,
+        1 => RotationState::Right,
+        2 => RotationState::Spawn,
+        _ => RotationState::Left,
+    }
+}
+
+/// The minoes around the SRS rotation center, which is where fumen places tetrominoes from,
+/// give or take [center_adjustment].
+fn blocks(kind: TetrominoKind, rotation_state: RotationState) -> [Offset; 4] {
+    use TetrominoKind::*;
+
+    let spawn: [Offset; 4] = match kind {
+        I => [(0, 0), (-1, 0), (1, 0), (2, 0)],
+        T => [(0, 0), (-1, 0), (1, 0), (0, 1)],
+        O => [(0, 0), (1, 0), (0, 1), (1, 1)],
+        L => [(0, 0), (-1, 0), (1, 0), (1, 1)],
+        J => [(0, 0), (-1, 0), (1, 0), (-1, 1)],
+        S => [(0, 0), (-1, 0), (0, 1), (1, 1)],
+        Z => [(0, 0), (1, 0), (0, 1), (-1, 1)],
+    };
+
+    spawn.map(|(x, y)| match rotation_state {
+        RotationState::Spawn => (x, y),
+        RotationState::Right => (y, -x),
+        RotationState::Reverse => (-x, -y),
+        RotationState::Left => (-y, x),
+    })
+}
+
+/// Fumen predates SRS and keeps its own centers for O, I, S and Z in some rotation states.
+/// This is how far its center is from the SRS one.
+fn center_adjustment(kind: TetrominoKind, rotation_state: RotationState) -> Offset {
+    use RotationState::*;
+    use TetrominoKind::*;
+
+    match (kind, rotation_state) {
+        (O, Spawn) | (I, Left) | (S, Spawn) | (Z, Spawn) => (0, 1),
+        (O, Reverse) | (I, Reverse) | (Z, Left) => (-1, 0),
+        (O, Left) => (-1, 1),
+        (S, Right) => (1, 0),
+        _ => (0, 0),
+    }
+}
+
+/// The bottom left corner of a set of offsets.
+fn corner(offsets: &[Offset; 4]) -> Offset {
+    offsets
+        .iter()
+        .fold((i8::MAX, i8::MAX), |(x_min, y_min), &(x, y)| {
+            (x_min.min(x), y_min.min(y))
+        })
+}
+
+fn encode_coordinate(tetromino: &Tetromino) -> Result<u32, FumenError> {
+    let kind = tetromino.kind();
+    let rotation_state = tetromino.rotation_state();
+
+    let minoes = tetromino
+        .minoes()
+        .map(|Position { row, column }| (column, row));
+
+    let (minoes_x, minoes_y) = corner(&minoes);
+    let (blocks_x, blocks_y) = corner(&blocks(kind, rotation_state));
+    let (adjustment_x, adjustment_y) = center_adjustment(kind, rotation_state);
+
+    let x = i32::from(minoes_x - blocks_x + adjustment_x);
+    let y = i32::from(minoes_y - blocks_y + adjustment_y);
+
+    match (0..PLAYFIELD_COLUMNS as i32).contains(&x) && (-1..FIELD_ROWS as i32).contains(&y) {
+        true => Ok(((FIELD_ROWS as i32 - y - 1) * PLAYFIELD_COLUMNS as i32 + x) as u32),
+        false => Err(FumenError::OutOfField),
+    }
+}
+
+fn decode_tetromino(
+    kind: TetrominoKind,
+    rotation_state: RotationState,
+    coordinate: u32,
+) -> Tetromino {
+    let x = (coordinate % PLAYFIELD_COLUMNS as u32) as i8;
+    let y = FIELD_ROWS as i8 - (coordinate / PLAYFIELD_COLUMNS as u32) as i8 - 1;
+
+    let (adjustment_x, adjustment_y) = center_adjustment(kind, rotation_state);
+    let (blocks_x, blocks_y) = corner(&blocks(kind, rotation_state));
+    let (minoes_x, minoes_y) = corner(&kind.minoes(rotation_state));
+
+    kind.placed(
+        rotation_state,
+        Position {
+            row: y - adjustment_y + blocks_y - minoes_y,
+            column: x - adjustment_x + blocks_x - minoes_x,
+        },
+    )
+}
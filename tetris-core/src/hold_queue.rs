@@ -0,0 +1,43 @@
+use super::tetromino::TetrominoKind;
+
+/// The tetromino set aside with [crate::TetrisMove::Hold], usable once per lock.
+#[derive(Clone)]
+pub struct HoldQueue {
+    held: Option<TetrominoKind>,
+    can_hold: bool,
+}
+
+impl HoldQueue {
+    pub(crate) fn new() -> Self {
+        HoldQueue {
+            held: None,
+            can_hold: true,
+        }
+    }
+
+    /// Stores `tetromino_kind` and hands back whatever was held before it. Returns [None] when a
+    /// hold has already been used since the last lock.
+    pub(crate) fn hold(&mut self, tetromino_kind: TetrominoKind) -> Option<Option<TetrominoKind>> {
+        if !self.can_hold {
+            return None;
+        }
+
+        self.can_hold = false;
+
+        Some(self.held.replace(tetromino_kind))
+    }
+
+    /// The tetromino being held, if any.
+    pub fn held(&self) -> Option<TetrominoKind> {
+        self.held
+    }
+
+    /// Whether a hold is still available before the falling tetromino locks.
+    pub fn can_hold(&self) -> bool {
+        self.can_hold
+    }
+
+    pub(crate) fn release(&mut self) {
+        self.can_hold = true;
+    }
+}
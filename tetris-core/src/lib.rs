@@ -0,0 +1,42 @@
+//! The rules of Tetris without any rendering or input handling.
+//!
+//! A [Tetris] is advanced one frame at a time with [Tetris::tick], given the [Moves] made
+//! during that frame. [FixedTimestep] turns the time between rendered frames into ticks.
+//! Everything needed to draw it is exposed through read-only accessors.
+
+#![warn(missing_docs)]
+
+/// Converting boards to and from fumen, the format setups are shared in.
+pub mod fumen;
+/// The tetromino set aside for later.
+pub mod hold_queue;
+/// The grid tetrominoes lock into.
+pub mod matrix;
+/// The preview of the tetrominoes to come.
+pub mod next_queue;
+/// The moves a player can make.
+pub mod player;
+mod random;
+/// The order tetrominoes are dealt in.
+pub mod randomizer;
+/// Recording games and playing them back.
+pub mod replay;
+/// Points, lines and levels.
+pub mod scoring;
+/// The rules a game is played by.
+pub mod settings;
+/// A single game of Tetris.
+#[allow(clippy::module_inception)]
+pub mod tetris;
+/// The pieces and how they move.
+pub mod tetromino;
+/// Turning frame times into ticks.
+pub mod timestep;
+
+pub use player::{Moves, Player, TetrisMove};
+pub use randomizer::RandomizerKind;
+pub use replay::{Playback, Recording, Replay};
+pub use settings::Settings;
+pub use tetris::{Tetris, TetrisEvent, TetrisState, TopOut, TICK, TICKS_PER_SECOND};
+pub use tetromino::{Rotation, Step, TetrominoKind};
+pub use timestep::FixedTimestep;
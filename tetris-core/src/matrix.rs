@@ -0,0 +1,204 @@
+use super::tetromino::{LastMove, Position, Tetromino, TetrominoKind};
+
+/// The rows of the matrix that are visible.
+pub const PLAYFIELD_ROWS: usize = 20;
+/// The width of the matrix.
+pub const PLAYFIELD_COLUMNS: usize = 10;
+
+/// Hidden rows stacked on top of the visible playfield, where tetrominoes spawn and where the
+/// stack can be pushed without topping out.
+pub const VANISH_ZONE_ROWS: usize = 20;
+/// Every row of the matrix, visible or not.
+pub const MATRIX_ROWS: usize = PLAYFIELD_ROWS + VANISH_ZONE_ROWS;
+
+/// The grid of cells that tetrominoes lock into, including the vanish zone. Row 0 is the bottom.
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct Matrix {
+    cells: [[Cell; PLAYFIELD_COLUMNS]; MATRIX_ROWS],
+}
+
+impl Default for Matrix {
+    fn default() -> Self {
+        Matrix::new()
+    }
+}
+
+impl Matrix {
+    /// An empty matrix.
+    pub fn new() -> Self {
+        Matrix {
+            cells: [[Cell::Empty; PLAYFIELD_COLUMNS]; MATRIX_ROWS],
+        }
+    }
+
+    /// Fills the cells covered by `tetromino`.
+    pub fn solidify(mut self, tetromino: &Tetromino) -> Self {
+        for position in tetromino.minoes() {
+            self.cells[position.row as usize][position.column as usize] =
+                Cell::Filled(tetromino.kind());
+        }
+
+        self
+    }
+
+    /// Sets the cell at `position`, for building up a matrix by hand. Positions outside of the
+    /// matrix are left alone.
+    pub fn with_cell(mut self, Position { row, column }: &Position, cell: Cell) -> Self {
+        if let (Ok(row), Ok(column)) = (usize::try_from(*row), usize::try_from(*column)) {
+            if let Some(target) = self
+                .cells
+                .get_mut(row)
+                .and_then(|line| line.get_mut(column))
+            {
+                *target = cell;
+            }
+        }
+
+        self
+    }
+
+    /// The cell at `position`, or [None] when it lies outside of the matrix.
+    pub fn cell(&self, Position { row, column }: &Position) -> Option<Cell> {
+        self.cells
+            .get(usize::try_from(*row).ok()?)?
+            .get(usize::try_from(*column).ok()?)
+            .copied()
+    }
+
+    /// Whether every cell is empty, as after a perfect clear.
+    pub fn is_empty(&self) -> bool {
+        self.cells
+            .iter()
+            .all(|line| line.iter().all(|&cell| cell == Cell::Empty))
+    }
+
+    /// How many lines are filled and waiting to be cleared.
+    pub fn filled_lines(&self) -> usize {
+        self.cells.iter().filter(|line| line.filled()).count()
+    }
+
+    /// Removes every filled line, shifting the lines above them down, and reports how many lines
+    /// were cleared.
+    pub fn clear_lines(mut self) -> (Self, u8) {
+        fn clear_lines(matrix: &mut [[Cell; PLAYFIELD_COLUMNS]]) -> u8 {
+            let length = matrix.len();
+
+            for (index, line) in matrix.iter().enumerate() {
+                if line.filled() {
+                    let shift_down_range = (index + 1)..length;
+
+                    let cleared = clear_lines(&mut matrix[shift_down_range.clone()]);
+                    matrix.copy_within(shift_down_range, index);
+
+                    matrix[length - 1] = [Cell::Empty; PLAYFIELD_COLUMNS];
+
+                    return cleared + 1;
+                }
+            }
+
+            0
+        }
+
+        let cleared = clear_lines(&mut self.cells);
+
+        (self, cleared)
+    }
+
+    /// How many rows `tetromino` can fall before it would collide with the stack or the floor.
+    pub fn drop_distance(&self, tetromino: &Tetromino) -> u8 {
+        let mut rows = 0;
+
+        while self.validate(&tetromino.clone().drop(rows + 1)) == TetrominoValidity::Valid {
+            rows += 1;
+        }
+
+        rows
+    }
+
+    /// Applies the 3-corner rule to a T tetromino that is about to lock. Both corners in front of
+    /// its point being filled makes a full T-spin, only one of them a mini, unless the last kick
+    /// of the rotation was needed to get there.
+    pub fn t_spin(&self, tetromino: &Tetromino) -> TSpin {
+        const LAST_KICK: u8 = 4;
+
+        let kick = match (tetromino.kind(), tetromino.last_move()) {
+            (TetrominoKind::T, LastMove::Rotation { kick }) => kick,
+            _ => return TSpin::None,
+        };
+
+        let (front, back) = tetromino.corners();
+
+        let occupied = |corners: [Position; 2]| {
+            corners
+                .iter()
+                .filter(|corner| self.occupied(corner))
+                .count()
+        };
+
+        match (occupied(front), occupied(back)) {
+            (2, 1..) => TSpin::Full,
+            (1, 2) if kick == LAST_KICK => TSpin::Full,
+            (1, 2) => TSpin::Mini,
+            _ => TSpin::None,
+        }
+    }
+
+    fn occupied(&self, Position { row, column }: &Position) -> bool {
+        !(0..(MATRIX_ROWS as i8)).contains(row)
+            || !(0..(PLAYFIELD_COLUMNS as i8)).contains(column)
+            || self.cells[*row as usize][*column as usize] != Cell::Empty
+    }
+
+    /// Whether `tetromino` fits, lying inside of the matrix without overlapping any cells.
+    pub fn validate(&self, tetromino: &Tetromino) -> TetrominoValidity {
+        match tetromino.minoes().iter().all(|Position { row, column }| {
+            (0..(MATRIX_ROWS as i8)).contains(row)
+                && (0..(PLAYFIELD_COLUMNS as i8)).contains(column)
+                && self.cells[*row as usize][*column as usize] == Cell::Empty
+        }) {
+            true => TetrominoValidity::Valid,
+            false => TetrominoValidity::Invalid,
+        }
+    }
+}
+
+trait RowExtension {
+    fn filled(&self) -> bool;
+}
+
+impl RowExtension for [Cell; PLAYFIELD_COLUMNS] {
+    fn filled(&self) -> bool {
+        self.iter().all(|&cell| cell != Cell::Empty)
+    }
+}
+
+/// What a cell of the matrix holds.
+#[derive(Debug, PartialEq, Eq, Clone, Copy)]
+pub enum Cell {
+    /// Left behind by a locked tetromino of the given kind.
+    Filled(TetrominoKind),
+    /// Pushed up from below the stack rather than locked by the player.
+    Garbage,
+    /// Nothing.
+    Empty,
+}
+
+/// How a T-spin is scored, see [Matrix::t_spin].
+#[derive(Debug, PartialEq, Eq, Clone, Copy)]
+pub enum TSpin {
+    /// Not a T-spin.
+    None,
+    /// Only one of the corners in front of the point is filled, scored lower.
+    Mini,
+    /// A proper T-spin.
+    Full,
+}
+
+/// Whether a tetromino fits in the matrix, see [Matrix::validate].
+#[derive(Debug, PartialEq, Eq)]
+pub enum TetrominoValidity {
+    /// It fits.
+    Valid,
+    /// It's outside of the matrix or overlapping a cell.
+    Invalid,
+}
@@ -0,0 +1,62 @@
+use super::randomizer::Randomizer;
+use super::tetromino::TetrominoKind;
+
+/// Deals tetrominoes from a [Randomizer] while previewing the next `SIZE` of them. A randomizer
+/// picked at runtime is dealt from as the `Box<dyn Randomizer>` that
+/// [super::randomizer::RandomizerKind::with_seed] returns.
+#[derive(Clone)]
+pub struct NextQueue<R: Iterator<Item = TetrominoKind>, const SIZE: usize> {
+    randomizer: R,
+    upcoming: [TetrominoKind; SIZE],
+    queue_item_index: usize,
+}
+
+impl<R: Iterator<Item = TetrominoKind>, const SIZE: usize> NextQueue<R, SIZE> {
+    /// Fills the preview from `randomizer`.
+    pub fn new(mut randomizer: R) -> Self {
+        const { assert!(SIZE > 0, "A [NextQueue] can't be empty") };
+
+        NextQueue {
+            upcoming: randomizer
+                .by_ref()
+                .take(SIZE)
+                .collect::<Vec<TetrominoKind>>()
+                .try_into()
+                .expect("Should be safe because [Randomizer::next] will never return [None]"),
+            randomizer,
+            queue_item_index: 0,
+        }
+    }
+
+    /// The previewed tetrominoes, the one dealt next first.
+    pub fn upcoming(&self) -> impl Iterator<Item = &TetrominoKind> {
+        self.upcoming
+            .iter()
+            .cycle()
+            .skip(self.queue_item_index)
+            .take(SIZE)
+    }
+}
+
+impl<R: Randomizer, const SIZE: usize> NextQueue<R, SIZE> {
+    /// A queue whose pieces are entirely determined by `seed`.
+    pub fn with_seed(seed: u64) -> Self {
+        NextQueue::new(R::with_seed(seed))
+    }
+}
+
+impl<R: Iterator<Item = TetrominoKind>, const SIZE: usize> Iterator for NextQueue<R, SIZE> {
+    type Item = TetrominoKind;
+
+    fn next(&mut self) -> Option<Self::Item> {
+        let next_piece = self.upcoming[self.queue_item_index];
+
+        self.upcoming[self.queue_item_index] = self
+            .randomizer
+            .next()
+            .expect("Should be safe because [self.randomizer.next] will never return [None]");
+        self.queue_item_index = (self.queue_item_index + 1) % SIZE;
+
+        Some(next_piece)
+    }
+}
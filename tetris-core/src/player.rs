@@ -0,0 +1,31 @@
+use super::tetromino::{Rotation, Step};
+
+/// An input to apply to the falling tetromino, see [crate::Tetris::tick].
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub enum TetrisMove {
+    /// Rotates the tetromino, kicking it off of walls and the stack if needed.
+    Rotate(Rotation),
+    /// Drops the tetromino as far as it goes and locks it at once.
+    HardDrop,
+    /// Makes the tetromino fall faster for as long as it is held.
+    SoftDrop,
+    /// Moves the tetromino a column over, repeating after a delay for as long as it is held.
+    Shift(Step),
+    /// Swaps the tetromino with the held one.
+    Hold,
+}
+
+impl TetrisMove {
+    /// Whether the move lasts for as long as its key is held, rather than happening once per
+    /// press.
+    pub fn is_held(&self) -> bool {
+        matches!(self, TetrisMove::Shift(_) | TetrisMove::SoftDrop)
+    }
+}
+
+/// Every move made during a single frame.
+pub type Moves = Vec<TetrisMove>;
+
+/// Anything that produces the moves for each frame, such as a keyboard or a recording.
+pub trait Player: Iterator<Item = Moves> {}
+impl<T: Iterator<Item = Moves>> Player for T {}
@@ -0,0 +1,88 @@
+/// The PCG32 generator (PCG-XSH-RR with 64 bits of state and 32 bits of output) described by
+/// Melissa O'Neill at <https://www.pcg-random.org>. It is spelled out here rather than taken
+/// from `rand` so that a seed produces the same pieces on every platform and every version of
+/// the game.
+#[derive(Debug, Clone)]
+pub(crate) struct Pcg32 {
+    state: u64,
+    increment: u64,
+}
+
+impl Pcg32 {
+    const MULTIPLIER: u64 = 6364136223846793005;
+    const INCREMENT: u64 = 1442695040888963407;
+
+    pub(crate) fn new(seed: u64) -> Self {
+        let mut pcg = Pcg32 {
+            state: 0,
+            increment: Self::INCREMENT,
+        };
+
+        pcg.next_u32();
+        pcg.state = pcg.state.wrapping_add(seed);
+        pcg.next_u32();
+
+        pcg
+    }
+
+    pub(crate) fn next_u32(&mut self) -> u32 {
+        let state = self.state;
+
+        self.state = state
+            .wrapping_mul(Self::MULTIPLIER)
+            .wrapping_add(self.increment);
+
+        let xor_shifted = (((state >> 18) ^ state) >> 27) as u32;
+        let rotation = (state >> 59) as u32;
+
+        xor_shifted.rotate_right(rotation)
+    }
+
+    /// A uniformly distributed number in `0..bound`, rejecting the outputs that would bias the
+    /// result towards smaller numbers.
+    pub(crate) fn below(&mut self, bound: u32) -> u32 {
+        let threshold = bound.wrapping_neg() % bound;
+
+        loop {
+            let random = self.next_u32();
+
+            if random >= threshold {
+                return random % bound;
+            }
+        }
+    }
+
+    /// A Fisher-Yates shuffle driven by [Pcg32::below].
+    pub(crate) fn shuffle<T>(&mut self, items: &mut [T]) {
+        for index in (1..items.len()).rev() {
+            let swap_index = self.below(index as u32 + 1) as usize;
+            items.swap(index, swap_index);
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn matches_the_reference_implementation() {
+        // The first outputs of `pcg32_srandom(42, 54)` from the PCG C library's demo, seeded
+        // the same way as [Pcg32::new] but on stream 54 rather than the default one.
+        let mut pcg = Pcg32 {
+            state: 0,
+            increment: (54 << 1) | 1,
+        };
+
+        pcg.next_u32();
+        pcg.state = pcg.state.wrapping_add(42);
+        pcg.next_u32();
+
+        let outputs: Vec<u32> = (0..6).map(|_| pcg.next_u32()).collect();
+
+        assert_eq!(
+            outputs,
+            [0xa15c02b7, 0x7b47f409, 0xba1d3330, 0x83d2f293, 0xbfa4784b, 0xcbed606e]
+        );
+    }
+}
@@ -0,0 +1,345 @@
+use super::random::Pcg32;
+use super::tetromino::TetrominoKind;
+
+use strum::{EnumCount, IntoEnumIterator};
+
+/// Decides the order in which tetrominoes are dealt. Implementors are endless, so
+/// [Iterator::next] must never return [None].
+pub trait Randomizer: Iterator<Item = TetrominoKind> + CloneRandomizer {
+    /// A randomizer whose tetrominoes are entirely determined by `seed`.
+    fn with_seed(seed: u64) -> Self
+    where
+        Self: Sized;
+}
+
+/// Lets a boxed randomizer be cloned along with the game it deals for. Implemented for every
+/// [Randomizer] that is [Clone].
+pub trait CloneRandomizer {
+    /// Clones the randomizer into a new box.
+    fn clone_boxed(&self) -> Box<dyn Randomizer>;
+}
+
+impl<R: Randomizer + Clone + 'static> CloneRandomizer for R {
+    fn clone_boxed(&self) -> Box<dyn Randomizer> {
+        Box::new(self.clone())
+    }
+}
+
+impl Clone for Box<dyn Randomizer> {
+    fn clone(&self) -> Self {
+        (**self).clone_boxed()
+    }
+}
+
+/// Every randomizer that ships with the game, so that one can be picked at runtime.
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum RandomizerKind {
+    /// See [SevenBag].
+    SevenBag,
+    /// See [FourteenBag].
+    FourteenBag,
+    /// See [Memoryless].
+    Memoryless,
+    /// See [Nes].
+    Nes,
+    /// See [Tgm].
+    Tgm,
+    /// See [Tgm2].
+    Tgm2,
+    /// See [Tgm3].
+    Tgm3,
+}
+
+impl RandomizerKind {
+    /// The randomizer of this kind seeded with `seed`.
+    pub fn with_seed(self, seed: u64) -> Box<dyn Randomizer> {
+        match self {
+            RandomizerKind::SevenBag => Box::new(SevenBag::with_seed(seed)),
+            RandomizerKind::FourteenBag => Box::new(FourteenBag::with_seed(seed)),
+            RandomizerKind::Memoryless => Box::new(Memoryless::with_seed(seed)),
+            RandomizerKind::Nes => Box::new(Nes::with_seed(seed)),
+            RandomizerKind::Tgm => Box::new(Tgm::with_seed(seed)),
+            RandomizerKind::Tgm2 => Box::new(Tgm2::with_seed(seed)),
+            RandomizerKind::Tgm3 => Box::new(Tgm3::with_seed(seed)),
+        }
+    }
+}
+
+fn random_kind(randomizer: &mut Pcg32, kinds: &[TetrominoKind]) -> TetrominoKind {
+    kinds[randomizer.below(kinds.len() as u32) as usize]
+}
+
+fn any_kind(randomizer: &mut Pcg32) -> TetrominoKind {
+    TetrominoKind::iter()
+        .nth(randomizer.below(TetrominoKind::COUNT as u32) as usize)
+        .expect("Should be safe because [Pcg32::below] is less than [TetrominoKind::COUNT]")
+}
+
+/// Deals shuffled bags holding `COPIES` of every tetromino, one bag after another.
+#[derive(Clone)]
+pub struct Bag<const COPIES: usize> {
+    tetrominoes: Vec<TetrominoKind>,
+    bag_item_index: usize,
+    randomizer: Pcg32,
+}
+
+/// The guideline randomizer.
+pub type SevenBag = Bag<1>;
+/// Two of every tetromino per bag, allowing longer droughts and repeats than [SevenBag].
+pub type FourteenBag = Bag<2>;
+
+impl<const COPIES: usize> Randomizer for Bag<COPIES> {
+    fn with_seed(seed: u64) -> Self {
+        let mut randomizer = Pcg32::new(seed);
+
+        let mut tetrominoes: Vec<TetrominoKind> = TetrominoKind::iter()
+            .flat_map(|kind| std::iter::repeat_n(kind, COPIES))
+            .collect();
+
+        randomizer.shuffle(&mut tetrominoes);
+
+        Bag {
+            tetrominoes,
+            bag_item_index: 0,
+            randomizer,
+        }
+    }
+}
+
+impl<const COPIES: usize> Iterator for Bag<COPIES> {
+    type Item = TetrominoKind;
+
+    fn next(&mut self) -> Option<Self::Item> {
+        let current_piece = self.tetrominoes[self.bag_item_index];
+
+        if self.bag_item_index == self.tetrominoes.len() - 1 {
+            self.randomizer.shuffle(&mut self.tetrominoes);
+        }
+
+        self.bag_item_index = (self.bag_item_index + 1) % self.tetrominoes.len();
+
+        Some(current_piece)
+    }
+}
+
+/// Every tetromino is equally likely every time, regardless of what came before.
+#[derive(Clone)]
+pub struct Memoryless {
+    randomizer: Pcg32,
+}
+
+impl Randomizer for Memoryless {
+    fn with_seed(seed: u64) -> Self {
+        Memoryless {
+            randomizer: Pcg32::new(seed),
+        }
+    }
+}
+
+impl Iterator for Memoryless {
+    type Item = TetrominoKind;
+
+    fn next(&mut self) -> Option<Self::Item> {
+        Some(any_kind(&mut self.randomizer))
+    }
+}
+
+/// The NES randomizer. It rolls one of eight outcomes, and rerolls once among the seven
+/// tetrominoes when the roll lands on the eighth outcome or repeats the previous piece.
+#[derive(Clone)]
+pub struct Nes {
+    previous: Option<TetrominoKind>,
+    randomizer: Pcg32,
+}
+
+impl Randomizer for Nes {
+    fn with_seed(seed: u64) -> Self {
+        Nes {
+            previous: None,
+            randomizer: Pcg32::new(seed),
+        }
+    }
+}
+
+impl Iterator for Nes {
+    type Item = TetrominoKind;
+
+    fn next(&mut self) -> Option<Self::Item> {
+        let roll = self.randomizer.below(TetrominoKind::COUNT as u32 + 1) as usize;
+
+        let piece = match TetrominoKind::iter().nth(roll) {
+            Some(piece) if Some(piece) != self.previous => piece,
+            _ => any_kind(&mut self.randomizer),
+        };
+
+        self.previous = Some(piece);
+
+        Some(piece)
+    }
+}
+
+/// The first piece of the TGM randomizers, which is never an S, Z or O so that the game
+/// cannot open with an overhang.
+const TGM_FIRST_PIECES: [TetrominoKind; 4] = [
+    TetrominoKind::I,
+    TetrominoKind::J,
+    TetrominoKind::L,
+    TetrominoKind::T,
+];
+
+/// The TGM randomizers remember the last four pieces and roll up to `ROLLS` times for a piece
+/// outside of that history, settling for the last roll when every roll is a repeat.
+#[derive(Clone)]
+pub struct History<const ROLLS: u8> {
+    history: [TetrominoKind; 4],
+    first: bool,
+    randomizer: Pcg32,
+}
+
+/// Tetris The Grand Master, with a history starting out as four Zs.
+pub type Tgm = History<4>;
+
+/// Tetris The Absolute The Grand Master 2, with a history starting out as Z, S, S, Z.
+pub type Tgm2 = History<6>;
+
+impl<const ROLLS: u8> History<ROLLS> {
+    fn push(&mut self, piece: TetrominoKind) {
+        self.history.rotate_left(1);
+        self.history[self.history.len() - 1] = piece;
+    }
+}
+
+impl<const ROLLS: u8> Randomizer for History<ROLLS> {
+    fn with_seed(seed: u64) -> Self {
+        use TetrominoKind::{S, Z};
+
+        History {
+            history: match ROLLS {
+                4 => [Z, Z, Z, Z],
+                _ => [Z, S, S, Z],
+            },
+            first: true,
+            randomizer: Pcg32::new(seed),
+        }
+    }
+}
+
+impl<const ROLLS: u8> Iterator for History<ROLLS> {
+    type Item = TetrominoKind;
+
+    fn next(&mut self) -> Option<Self::Item> {
+        let piece = if self.first {
+            self.first = false;
+            random_kind(&mut self.randomizer, &TGM_FIRST_PIECES)
+        } else {
+            let mut piece = any_kind(&mut self.randomizer);
+
+            for _ in 1..ROLLS {
+                if !self.history.contains(&piece) {
+                    break;
+                }
+
+                piece = any_kind(&mut self.randomizer);
+            }
+
+            piece
+        };
+
+        self.push(piece);
+
+        Some(piece)
+    }
+}
+
+/// The Tetris The Grand Master 3 randomizer. Pieces are drawn from a pool of 35, five of every
+/// tetromino, with up to six rolls against a history of four. Every drawn piece is replaced in
+/// the pool by the piece that has gone the longest without being dealt, so droughts correct
+/// themselves.
+#[derive(Clone)]
+pub struct Tgm3 {
+    pool: Vec<TetrominoKind>,
+    history: [TetrominoKind; 4],
+    /// Dealt tetrominoes from the longest ago to the most recently dealt.
+    droughts: Vec<TetrominoKind>,
+    first: bool,
+    randomizer: Pcg32,
+}
+
+impl Tgm3 {
+    const COPIES: usize = 5;
+    const ROLLS: u8 = 6;
+
+    /// The tetromino that has gone without being dealt for the longest.
+    pub fn most_droughted(&self) -> Option<TetrominoKind> {
+        self.droughts.first().copied()
+    }
+
+    /// The 35 tetrominoes the next piece is drawn from.
+    pub fn pool(&self) -> &[TetrominoKind] {
+        &self.pool
+    }
+
+    fn push(&mut self, piece: TetrominoKind) {
+        self.history.rotate_left(1);
+        self.history[self.history.len() - 1] = piece;
+    }
+}
+
+impl Randomizer for Tgm3 {
+    fn with_seed(seed: u64) -> Self {
+        use TetrominoKind::{S, Z};
+
+        Tgm3 {
+            pool: TetrominoKind::iter()
+                .flat_map(|kind| std::iter::repeat_n(kind, Tgm3::COPIES))
+                .collect(),
+            history: [S, Z, S, Z],
+            droughts: Vec::with_capacity(TetrominoKind::COUNT),
+            first: true,
+            randomizer: Pcg32::new(seed),
+        }
+    }
+}
+
+impl Iterator for Tgm3 {
+    type Item = TetrominoKind;
+
+    fn next(&mut self) -> Option<Self::Item> {
+        if self.first {
+            self.first = false;
+
+            let piece = random_kind(&mut self.randomizer, &TGM_FIRST_PIECES);
+            self.push(piece);
+
+            return Some(piece);
+        }
+
+        let mut roll = 0;
+
+        let (index, piece) = loop {
+            let index = self.randomizer.below(self.pool.len() as u32) as usize;
+            let piece = self.pool[index];
+
+            roll += 1;
+
+            if !self.history.contains(&piece) || roll == Tgm3::ROLLS {
+                break (index, piece);
+            }
+
+            if let Some(most_droughted) = self.most_droughted() {
+                self.pool[index] = most_droughted;
+            }
+        };
+
+        self.droughts.retain(|&kind| kind != piece);
+        self.droughts.push(piece);
+
+        self.pool[index] = self
+            .most_droughted()
+            .expect("Should be safe because [piece] was just pushed to [self.droughts]");
+
+        self.push(piece);
+
+        Some(piece)
+    }
+}
@@ -0,0 +1,609 @@
+use std::fs;
+use std::io;
+use std::path::Path;
+use std::time::Duration;
+
+use super::player::{Moves, TetrisMove};
+use super::randomizer::RandomizerKind;
+use super::settings::Settings;
+use super::tetris::{Tetris, TetrisEvent, TetrisState};
+use super::tetromino::{Rotation, Step, SUBROWS_PER_ROW};
+
+const MAGIC: [u8; 4] = *b"TRPL";
+const VERSION: u8 = 1;
+
+/// Gravity is never below one subrow per frame, so a larger soft drop factor can't fall any
+/// faster than 20G.
+const MAX_SOFT_DROP_FACTOR: u32 = 20 * SUBROWS_PER_ROW;
+
+/// Every tick of a game, enough to play it back exactly: the seed and settings it started
+/// with, and the moves fed into each [Tetris::tick].
+#[derive(Debug, Clone, PartialEq)]
+pub struct Recording {
+    seed: u64,
+    settings: Settings,
+    ticks: u64,
+    runs: Vec<Run>,
+}
+
+/// The same moves made on `length` ticks in a row, starting at tick `start`. Ticks that aren't
+/// covered by any run had no moves at all.
+#[derive(Debug, Clone, PartialEq, Eq)]
+struct Run {
+    start: u64,
+    length: u64,
+    moves: Moves,
+}
+
+impl Run {
+    fn end(&self) -> u64 {
+        self.start + self.length
+    }
+}
+
+impl Recording {
+    /// An empty recording of a game started from `seed` with `settings`.
+    pub fn new(seed: u64, settings: Settings) -> Self {
+        Recording {
+            seed,
+            settings,
+            ticks: 0,
+            runs: Vec::new(),
+        }
+    }
+
+    /// Starts recording a game that hasn't been ticked yet.
+    pub fn of(tetris: &Tetris) -> Self {
+        Recording::new(tetris.seed(), tetris.settings().clone())
+    }
+
+    /// The seed the recorded game was started from.
+    pub fn seed(&self) -> u64 {
+        self.seed
+    }
+
+    /// The settings the recorded game was played with.
+    pub fn settings(&self) -> &Settings {
+        &self.settings
+    }
+
+    /// How many ticks have been recorded.
+    pub fn ticks(&self) -> u64 {
+        self.ticks
+    }
+
+    /// A new game in the same state as the recorded one before its first tick.
+    pub fn tetris(&self) -> Tetris {
+        Tetris::with_settings_and_seed(self.settings.clone(), self.seed)
+    }
+
+    /// Records the moves fed into the next tick. Repeated moves are left out, since
+    /// [Tetris::tick] only applies each move once per tick anyway.
+    pub fn record(&mut self, moves: &Moves) {
+        let moves = moves.iter().fold(Moves::new(), |mut unique, tetris_move| {
+            if !unique.contains(tetris_move) {
+                unique.push(tetris_move.clone());
+            }
+
+            unique
+        });
+
+        if !moves.is_empty() {
+            match self.runs.last_mut() {
+                Some(run) if run.end() == self.ticks && run.moves == moves => run.length += 1,
+                _ => self.runs.push(Run {
+                    start: self.ticks,
+                    length: 1,
+                    moves,
+                }),
+            }
+        }
+
+        self.ticks += 1;
+    }
+
+    /// The moves fed into `tick`.
+    fn moves(&self, tick: u64) -> Moves {
+        let index = self.runs.partition_point(|run| run.end() <= tick);
+
+        match self.runs.get(index) {
+            Some(run) if run.start <= tick => run.moves.clone(),
+            _ => Vec::new(),
+        }
+    }
+
+    /// The compact binary replay format. Ticks are stored as runs of identical moves, so a key
+    /// held down for a second costs a few bytes rather than sixty batches.
+    pub fn encode(&self) -> Vec<u8> {
+        let mut bytes = Vec::new();
+
+        bytes.extend_from_slice(&MAGIC);
+        bytes.push(VERSION);
+        bytes.extend_from_slice(&self.seed.to_le_bytes());
+
+        let settings = &self.settings;
+
+        write_duration(&mut bytes, settings.lock_delay);
+        bytes.push(settings.move_reset_limit);
+        bytes.push(u8::from(settings.ghost_piece));
+        write_duration(&mut bytes, settings.ready_duration);
+        write_duration(&mut bytes, settings.line_clear_delay);
+        write_duration(&mut bytes, settings.delayed_auto_shift);
+        write_duration(&mut bytes, settings.auto_repeat_rate);
+        write_varint(&mut bytes, u64::from(settings.soft_drop_factor));
+        bytes.push(randomizer_code(settings.randomizer));
+
+        write_varint(&mut bytes, self.ticks);
+        write_varint(&mut bytes, self.runs.len() as u64);
+
+        let mut previous_end = 0;
+
+        for run in &self.runs {
+            write_varint(&mut bytes, run.start - previous_end);
+            write_varint(&mut bytes, run.length);
+            write_varint(&mut bytes, run.moves.len() as u64);
+            bytes.extend(run.moves.iter().map(move_code));
+
+            previous_end = run.end();
+        }
+
+        bytes
+    }
+
+    /// Reads a recording written by [Recording::encode].
+    pub fn decode(bytes: &[u8]) -> io::Result<Self> {
+        let mut reader = Reader { bytes };
+
+        if reader.take(MAGIC.len())? != MAGIC {
+            return Err(invalid_data("not a replay"));
+        }
+
+        let version = reader.byte()?;
+
+        if version != VERSION {
+            return Err(invalid_data(&format!(
+                "unsupported replay version {version}"
+            )));
+        }
+
+        let seed = u64::from_le_bytes(
+            reader
+                .take(8)?
+                .try_into()
+                .expect("Should be safe because [Reader::take] returned exactly 8 bytes"),
+        );
+
+        let settings = Settings {
+            lock_delay: reader.duration()?,
+            move_reset_limit: reader.byte()?,
+            ghost_piece: reader.byte()? != 0,
+            ready_duration: reader.duration()?,
+            line_clear_delay: reader.duration()?,
+            delayed_auto_shift: reader.duration()?,
+            auto_repeat_rate: reader.duration()?,
+            soft_drop_factor: u32::try_from(reader.varint()?)
+                .ok()
+                .filter(|factor| (1..=MAX_SOFT_DROP_FACTOR).contains(factor))
+                .ok_or_else(|| invalid_data("soft drop factor out of range"))?,
+            randomizer: randomizer_from_code(reader.byte()?)?,
+        };
+
+        let ticks = reader.varint()?;
+        let run_count = reader.varint()?;
+
+        let mut runs = Vec::new();
+        let mut previous_end = 0_u64;
+
+        for _ in 0..run_count {
+            let start = previous_end
+                .checked_add(reader.varint()?)
+                .ok_or_else(|| invalid_data("run out of range"))?;
+            let length = reader.varint()?;
+            let move_count = reader.varint()?;
+
+            let moves = (0..move_count)
+                .map(|_| move_from_code(reader.byte()?))
+                .collect::<io::Result<Moves>>()?;
+
+            let run = Run {
+                start,
+                length,
+                moves,
+            };
+
+            if run.length == 0 || run.moves.is_empty() || run.start.saturating_add(length) > ticks {
+                return Err(invalid_data("run out of range"));
+            }
+
+            let repeated = |(index, tetris_move)| run.moves[..index].contains(tetris_move);
+
+            if run.moves.iter().enumerate().any(repeated) {
+                return Err(invalid_data("the same move twice in one tick"));
+            }
+
+            previous_end = run.end();
+            runs.push(run);
+        }
+
+        if !reader.bytes.is_empty() {
+            return Err(invalid_data("trailing bytes after the last run"));
+        }
+
+        Ok(Recording {
+            seed,
+            settings,
+            ticks,
+            runs,
+        })
+    }
+
+    /// A readable rendition of [Recording::encode] for debugging. It can't be read back.
+    pub fn to_json(&self) -> String {
+        let settings = &self.settings;
+
+        let runs = self
+            .runs
+            .iter()
+            .map(|run| {
+                let moves = run
+                    .moves
+                    .iter()
+                    .map(|tetris_move| format!("\"{tetris_move:?}\""))
+                    .collect::<Vec<String>>()
+                    .join(", ");
+
+                format!(
+                    "    {{ \"tick\": {}, \"length\": {}, \"moves\": [{moves}] }}",
+                    run.start, run.length
+                )
+            })
+            .collect::<Vec<String>>()
+            .join(",\n");
+
+        format!(
+            r#"{{
+  "version": {VERSION},
+  "seed": {},
+  "settings": {{
+    "lock_delay_nanos": {},
+    "move_reset_limit": {},
+    "ghost_piece": {},
+    "ready_duration_nanos": {},
+    "line_clear_delay_nanos": {},
+    "delayed_auto_shift_nanos": {},
+    "auto_repeat_rate_nanos": {},
+    "soft_drop_factor": {},
+    "randomizer": "{:?}"
+  }},
+  "ticks": {},
+  "runs": [
+{runs}
+  ]
+}}
+"#,
+            self.seed,
+            settings.lock_delay.as_nanos(),
+            settings.move_reset_limit,
+            settings.ghost_piece,
+            settings.ready_duration.as_nanos(),
+            settings.line_clear_delay.as_nanos(),
+            settings.delayed_auto_shift.as_nanos(),
+            settings.auto_repeat_rate.as_nanos(),
+            settings.soft_drop_factor,
+            settings.randomizer,
+            self.ticks,
+        )
+    }
+
+    /// Saves the recording to `path` in the binary replay format.
+    pub fn write(&self, path: impl AsRef<Path>) -> io::Result<()> {
+        fs::write(path, self.encode())
+    }
+
+    /// Saves the recording to `path` in the JSON debug format.
+    pub fn write_json(&self, path: impl AsRef<Path>) -> io::Result<()> {
+        fs::write(path, self.to_json())
+    }
+
+    /// Loads a recording saved by [Recording::write].
+    pub fn read(path: impl AsRef<Path>) -> io::Result<Self> {
+        Recording::decode(&fs::read(path)?)
+    }
+}
+
+/// Plays a [Recording] back one tick at a time, yielding the same moves that were recorded.
+/// Ends once every recorded tick has been played.
+pub struct Replay {
+    recording: Recording,
+    tick: u64,
+}
+
+impl Replay {
+    /// Plays `recording` back from its first tick.
+    pub fn new(recording: Recording) -> Self {
+        Replay { recording, tick: 0 }
+    }
+
+    /// The recording being played back.
+    pub fn recording(&self) -> &Recording {
+        &self.recording
+    }
+
+    /// How many ticks have been played so far.
+    pub fn tick(&self) -> u64 {
+        self.tick
+    }
+
+    /// Continues playing from `tick`, as if that many ticks had already been played.
+    pub fn seek(&mut self, tick: u64) {
+        self.tick = tick.min(self.recording.ticks);
+    }
+}
+
+impl Iterator for Replay {
+    type Item = Moves;
+
+    fn next(&mut self) -> Option<Self::Item> {
+        if self.tick >= self.recording.ticks {
+            return None;
+        }
+
+        let moves = self.recording.moves(self.tick);
+        self.tick += 1;
+
+        Some(moves)
+    }
+}
+
+/// Ticks between the snapshots a [Playback] keeps, ten seconds of play at 60 ticks a second.
+const SNAPSHOT_INTERVAL: u64 = 600;
+
+/// A [Replay] that can be stepped through and rewound. Snapshots of the game are kept as it is
+/// played, so that seeking only has to re-simulate from the closest one instead of from the
+/// very first tick.
+pub struct Playback {
+    replay: Replay,
+    tetris: Tetris,
+    /// The moves fed into the last tick, or none right after seeking.
+    moves: Moves,
+    locks: u32,
+    snapshots: Vec<Snapshot>,
+}
+
+struct Snapshot {
+    tick: u64,
+    locks: u32,
+    tetris: Tetris,
+}
+
+impl Playback {
+    /// Starts watching `recording` from its first tick.
+    pub fn new(recording: Recording) -> Self {
+        let tetris = recording.tetris();
+
+        Playback {
+            snapshots: vec![Snapshot {
+                tick: 0,
+                locks: 0,
+                tetris: tetris.clone(),
+            }],
+            replay: Replay::new(recording),
+            tetris,
+            moves: Vec::new(),
+            locks: 0,
+        }
+    }
+
+    /// The game as of the current tick.
+    pub fn tetris(&self) -> &Tetris {
+        &self.tetris
+    }
+
+    /// The recording being played back.
+    pub fn recording(&self) -> &Recording {
+        self.replay.recording()
+    }
+
+    /// How many ticks have been played so far.
+    pub fn tick(&self) -> u64 {
+        self.replay.tick()
+    }
+
+    /// The moves fed into the last tick.
+    pub fn moves(&self) -> &Moves {
+        &self.moves
+    }
+
+    /// The number of the falling tetromino, counting from 1 for the first one.
+    pub fn piece(&self) -> u32 {
+        self.locks + 1
+    }
+
+    /// Whether every recorded tick has been played.
+    pub fn finished(&self) -> bool {
+        self.tick() >= self.recording().ticks()
+    }
+
+    /// Plays the next tick, returning [false] when there are none left.
+    pub fn step(&mut self) -> bool {
+        let Some(moves) = self.replay.next() else {
+            return false;
+        };
+
+        self.tetris = self.tetris.clone().tick(moves.clone());
+        self.moves = moves;
+
+        self.locks += self
+            .tetris
+            .events()
+            .iter()
+            .filter(|event| matches!(event, TetrisEvent::Lock(_)))
+            .count() as u32;
+
+        let tick = self.tick();
+
+        if tick.is_multiple_of(SNAPSHOT_INTERVAL)
+            && self
+                .snapshots
+                .last()
+                .is_some_and(|snapshot| snapshot.tick < tick)
+        {
+            self.snapshots.push(Snapshot {
+                tick,
+                locks: self.locks,
+                tetris: self.tetris.clone(),
+            });
+        }
+
+        true
+    }
+
+    /// Goes to right before `tick` is played.
+    pub fn seek(&mut self, tick: u64) {
+        self.restore(|snapshot| snapshot.tick <= tick);
+
+        while self.tick() < tick && self.step() {}
+    }
+
+    /// Goes to where tetromino number `piece` starts falling, or to the end of the replay if
+    /// fewer tetrominoes were played.
+    pub fn seek_piece(&mut self, piece: u32) {
+        let locks = piece.saturating_sub(1);
+
+        // A snapshot with exactly `locks` locks may be partway through the piece already.
+        self.restore(|snapshot| snapshot.locks < locks);
+
+        while (self.locks < locks
+            || matches!(self.tetris.state(), TetrisState::LineClearDelay { .. }))
+            && self.step()
+        {}
+    }
+
+    /// Goes back to the latest snapshot matching `usable`, or to the start when there is none.
+    fn restore(&mut self, usable: impl Fn(&Snapshot) -> bool) {
+        let snapshot = self
+            .snapshots
+            .iter()
+            .rev()
+            .find(|snapshot| usable(snapshot))
+            .unwrap_or(&self.snapshots[0]);
+
+        self.tetris = snapshot.tetris.clone();
+        self.locks = snapshot.locks;
+        self.replay.seek(snapshot.tick);
+        self.moves = Vec::new();
+    }
+}
+
+fn invalid_data(message: &str) -> io::Error {
+    io::Error::new(io::ErrorKind::InvalidData, message)
+}
+
+/// Unsigned LEB128, so that the small numbers that make up most of a replay take a single byte.
+fn write_varint(bytes: &mut Vec<u8>, mut value: u64) {
+    loop {
+        let byte = (value & 0x7F) as u8;
+        value >>= 7;
+
+        if value == 0 {
+            bytes.push(byte);
+            return;
+        }
+
+        bytes.push(byte | 0x80);
+    }
+}
+
+fn write_duration(bytes: &mut Vec<u8>, duration: Duration) {
+    write_varint(bytes, duration.as_nanos() as u64);
+}
+
+struct Reader<'a> {
+    bytes: &'a [u8],
+}
+
+impl<'a> Reader<'a> {
+    fn take(&mut self, count: usize) -> io::Result<&'a [u8]> {
+        if self.bytes.len() < count {
+            return Err(io::Error::from(io::ErrorKind::UnexpectedEof));
+        }
+
+        let (taken, rest) = self.bytes.split_at(count);
+        self.bytes = rest;
+
+        Ok(taken)
+    }
+
+    fn byte(&mut self) -> io::Result<u8> {
+        Ok(self.take(1)?[0])
+    }
+
+    fn varint(&mut self) -> io::Result<u64> {
+        let mut value = 0_u64;
+
+        for shift in (0..64).step_by(7) {
+            let byte = self.byte()?;
+            value |= u64::from(byte & 0x7F) << shift;
+
+            if byte & 0x80 == 0 {
+                return Ok(value);
+            }
+        }
+
+        Err(invalid_data("varint longer than 64 bits"))
+    }
+
+    fn duration(&mut self) -> io::Result<Duration> {
+        Ok(Duration::from_nanos(self.varint()?))
+    }
+}
+
+fn move_code(tetris_move: &TetrisMove) -> u8 {
+    match tetris_move {
+        TetrisMove::Rotate(Rotation::Clockwise) => 0,
+        TetrisMove::Rotate(Rotation::Counterclockwise) => 1,
+        TetrisMove::HardDrop => 2,
+        TetrisMove::SoftDrop => 3,
+        TetrisMove::Shift(Step::Left) => 4,
+        TetrisMove::Shift(Step::Right) => 5,
+        TetrisMove::Hold => 6,
+    }
+}
+
+fn move_from_code(code: u8) -> io::Result<TetrisMove> {
+    Ok(match code {
+        0 => TetrisMove::Rotate(Rotation::Clockwise),
+        1 => TetrisMove::Rotate(Rotation::Counterclockwise),
+        2 => TetrisMove::HardDrop,
+        3 => TetrisMove::SoftDrop,
+        4 => TetrisMove::Shift(Step::Left),
+        5 => TetrisMove::Shift(Step::Right),
+        6 => TetrisMove::Hold,
+        _ => return Err(invalid_data(&format!("unknown move {code}"))),
+    })
+}
+
+fn randomizer_code(randomizer: RandomizerKind) -> u8 {
+    match randomizer {
+        RandomizerKind::SevenBag => 0,
+        RandomizerKind::FourteenBag => 1,
+        RandomizerKind::Memoryless => 2,
+        RandomizerKind::Nes => 3,
+        RandomizerKind::Tgm => 4,
+        RandomizerKind::Tgm2 => 5,
+        RandomizerKind::Tgm3 => 6,
+    }
+}
+
+fn randomizer_from_code(code: u8) -> io::Result<RandomizerKind> {
+    Ok(match code {
+        0 => RandomizerKind::SevenBag,
+        1 => RandomizerKind::FourteenBag,
+        2 => RandomizerKind::Memoryless,
+        3 => RandomizerKind::Nes,
+        4 => RandomizerKind::Tgm,
+        5 => RandomizerKind::Tgm2,
+        6 => RandomizerKind::Tgm3,
+        _ => return Err(invalid_data(&format!("unknown randomizer {code}"))),
+    })
+}
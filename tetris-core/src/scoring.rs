@@ -0,0 +1,157 @@
+use super::matrix::TSpin;
+
+const LINES_PER_LEVEL: u32 = 10;
+
+/// Everything that happened when a tetromino locked, shared by scoring, garbage and the UI.
+#[derive(Debug, PartialEq, Eq, Clone, Copy)]
+pub struct LockResult {
+    /// How many lines the lock cleared.
+    pub lines: u8,
+    /// Whether the lock was a T-spin.
+    pub t_spin: TSpin,
+    /// How many line clearing locks came right before this one, if this lock cleared lines.
+    pub combo: Option<u32>,
+    /// Whether this and the last line clear were both difficult, a tetris or a T-spin.
+    pub back_to_back: bool,
+    /// Whether the lock left the matrix empty.
+    pub perfect_clear: bool,
+}
+
+impl LockResult {
+    /// Tetrises and T-spins that clear lines keep a back-to-back chain going.
+    pub fn difficult(&self) -> bool {
+        self.lines >= 4 || (self.t_spin != TSpin::None && self.lines > 0)
+    }
+}
+
+/// Remembers the combo and back-to-back chains across locks.
+#[derive(Clone)]
+pub(crate) struct Streaks {
+    combo: Option<u32>,
+    difficult_clear: bool,
+}
+
+impl Streaks {
+    pub(crate) fn new() -> Self {
+        Streaks {
+            combo: None,
+            difficult_clear: false,
+        }
+    }
+
+    pub(crate) fn lock(&mut self, lines: u8, t_spin: TSpin, perfect_clear: bool) -> LockResult {
+        let mut result = LockResult {
+            lines,
+            t_spin,
+            combo: None,
+            back_to_back: false,
+            perfect_clear,
+        };
+
+        if lines == 0 {
+            self.combo = None;
+            return result;
+        }
+
+        self.combo = Some(self.combo.map_or(0, |combo| combo + 1));
+
+        result.combo = self.combo;
+        result.back_to_back = result.difficult() && self.difficult_clear;
+
+        self.difficult_clear = result.difficult();
+
+        result
+    }
+}
+
+/// Points, cleared lines and level, scored according to the guideline.
+#[derive(Clone)]
+pub struct Score {
+    points: u64,
+    lines: u32,
+    level: u32,
+}
+
+impl Score {
+    pub(crate) fn new() -> Self {
+        Score {
+            points: 0,
+            lines: 0,
+            level: 1,
+        }
+    }
+
+    /// The score.
+    pub fn points(&self) -> u64 {
+        self.points
+    }
+
+    /// How many lines have been cleared.
+    pub fn lines(&self) -> u32 {
+        self.lines
+    }
+
+    /// The level, which goes up every ten lines and speeds up gravity.
+    pub fn level(&self) -> u32 {
+        self.level
+    }
+
+    pub(crate) fn lock(&mut self, result: &LockResult) {
+        let mut points = match (result.t_spin, result.lines) {
+            (TSpin::None, 0) => 0,
+            (TSpin::None, 1) => 100,
+            (TSpin::None, 2) => 300,
+            (TSpin::None, 3) => 500,
+            (TSpin::None, _) => 800,
+            (TSpin::Mini, 0) => 100,
+            (TSpin::Mini, 1) => 200,
+            (TSpin::Mini, _) => 400,
+            (TSpin::Full, 0) => 400,
+            (TSpin::Full, 1) => 800,
+            (TSpin::Full, 2) => 1200,
+            (TSpin::Full, _) => 1600,
+        };
+
+        if result.back_to_back {
+            points = points * 3 / 2;
+        }
+
+        if let Some(combo) = result.combo {
+            points += 50 * u64::from(combo);
+        }
+
+        if result.perfect_clear {
+            points += match result.lines {
+                1 => 800,
+                2 => 1200,
+                3 => 1800,
+                _ if result.back_to_back => 3200,
+                _ => 2000,
+            };
+        }
+
+        self.points += points * u64::from(self.level);
+        self.lines += u32::from(result.lines);
+        self.level = self.level.max(self.lines / LINES_PER_LEVEL + 1);
+    }
+
+    pub(crate) fn soft_drop(&mut self, rows: u8) {
+        self.points += u64::from(rows);
+    }
+
+    pub(crate) fn hard_drop(&mut self, rows: u8) {
+        self.points += 2 * u64::from(rows);
+    }
+
+    /// The gravity at the current level, in subrows per frame at 60 frames per second. Follows
+    /// the guideline curve of `(0.8 - (level - 1) * 0.007) ^ (level - 1)` seconds per row,
+    /// capped at 20G.
+    pub fn gravity(&self) -> u32 {
+        const GRAVITY: [u32; 19] = [
+            1092, 1377, 1768, 2311, 3075, 4169, 5759, 8107, 11634, 17026, 25416, 38709, 60169,
+            95483, 154742, 256187, 433425, 749597, 1310720,
+        ];
+
+        GRAVITY[(self.level as usize - 1).min(GRAVITY.len() - 1)]
+    }
+}
@@ -0,0 +1,42 @@
+use std::time::Duration;
+
+use super::randomizer::RandomizerKind;
+
+/// Handling and gameplay options, chosen when a [crate::Tetris] is created.
+#[derive(Debug, Clone, PartialEq)]
+pub struct Settings {
+    /// How long a grounded tetromino waits before it locks.
+    pub lock_delay: Duration,
+    /// How many times moving a grounded tetromino can restart its lock delay.
+    pub move_reset_limit: u8,
+    /// Whether [crate::Tetris::ghost_tetromino] shows where the tetromino would land.
+    pub ghost_piece: bool,
+    /// How long the countdown before the first tetromino falls lasts.
+    pub ready_duration: Duration,
+    /// How long the game pauses after clearing lines.
+    pub line_clear_delay: Duration,
+    /// How long a shift has to be held before it starts repeating.
+    pub delayed_auto_shift: Duration,
+    /// How often a fully charged shift repeats. Zero moves the tetromino straight to the wall.
+    pub auto_repeat_rate: Duration,
+    /// How many times faster than gravity a soft drop falls.
+    pub soft_drop_factor: u32,
+    /// The randomizer that deals the tetrominoes.
+    pub randomizer: RandomizerKind,
+}
+
+impl Default for Settings {
+    fn default() -> Self {
+        Settings {
+            lock_delay: Duration::from_millis(500),
+            move_reset_limit: 15,
+            ghost_piece: true,
+            ready_duration: Duration::from_secs(2),
+            line_clear_delay: Duration::from_millis(400),
+            delayed_auto_shift: Duration::from_millis(167),
+            auto_repeat_rate: Duration::from_millis(33),
+            soft_drop_factor: 20,
+            randomizer: RandomizerKind::SevenBag,
+        }
+    }
+}
@@ -0,0 +1,684 @@
+use super::hold_queue::HoldQueue;
+use super::matrix::{Matrix, TetrominoValidity, PLAYFIELD_COLUMNS, PLAYFIELD_ROWS};
+use super::next_queue::NextQueue;
+use super::player::{Moves, TetrisMove};
+use super::randomizer::Randomizer;
+use super::scoring::{LockResult, Score, Streaks};
+use super::settings::Settings;
+use super::tetromino::{Step, Tetromino, TetrominoKind};
+
+use std::time::Duration;
+
+/// How often the simulation advances.
+pub const TICKS_PER_SECOND: u32 = 60;
+
+/// The time covered by a single [Tetris::tick]. Rounded up to the next nanosecond, so that
+/// gravity is never truncated and a delay lasting a whole number of frames runs out on time.
+pub const TICK: Duration =
+    Duration::from_nanos(1_000_000_000_u64.div_ceil(TICKS_PER_SECOND as u64));
+
+/// Something that happened during a frame, see [Tetris::events].
+#[derive(Debug, PartialEq, Eq, Clone)]
+pub enum TetrisEvent {
+    /// The falling tetromino was hard dropped.
+    HardDrop {
+        /// How many rows it fell.
+        rows: u8,
+    },
+    /// A tetromino locked into the matrix.
+    Lock(LockResult),
+    /// A new tetromino started falling, dealt from the next queue or swapped out of the hold
+    /// queue.
+    Spawn(TetrominoKind),
+}
+
+/// What a game is doing, see [Tetris::state].
+#[derive(Debug, PartialEq, Eq, Clone, Copy)]
+pub enum TetrisState {
+    /// Counting down before the first tetromino falls.
+    Ready {
+        /// How long until it falls.
+        remaining: Duration,
+    },
+    /// A tetromino is falling.
+    Playing,
+    /// Stopped by [Tetris::toggle_pause].
+    Paused,
+    /// Waiting for cleared lines to disappear before the next tetromino spawns.
+    LineClearDelay {
+        /// How long until it spawns.
+        remaining: Duration,
+    },
+    /// The game has ended.
+    GameOver(TopOut),
+}
+
+/// Why the game ended.
+#[derive(Debug, PartialEq, Eq, Clone, Copy)]
+pub enum TopOut {
+    /// The next tetromino spawned overlapping the stack.
+    BlockOut,
+    /// A tetromino locked entirely above the visible matrix.
+    LockOut,
+}
+
+/// A single game of Tetris, advanced one frame at a time with [Tetris::tick].
+#[derive(Clone)]
+pub struct Tetris {
+    state: TetrisState,
+    matrix: Matrix,
+    falling_tetromino: Tetromino,
+    lock_delay: LockDelay,
+    auto_shift: AutoShift,
+    next_queue: NextQueue<Box<dyn Randomizer>, 5>,
+    seed: u64,
+    hold_queue: HoldQueue,
+    score: Score,
+    streaks: Streaks,
+    settings: Settings,
+    events: Vec<TetrisEvent>,
+}
+
+impl Default for Tetris {
+    fn default() -> Self {
+        Tetris::new()
+    }
+}
+
+impl Tetris {
+    /// A game with the default [Settings] and a random seed.
+    pub fn new() -> Self {
+        Tetris::with_settings(Settings::default())
+    }
+
+    /// A game with the given [Settings] and a random seed.
+    pub fn with_settings(settings: Settings) -> Self {
+        Tetris::with_settings_and_seed(settings, rand::random())
+    }
+
+    /// A game whose piece sequence is entirely determined by `seed`.
+    pub fn with_seed(seed: u64) -> Self {
+        Tetris::with_settings_and_seed(Settings::default(), seed)
+    }
+
+    /// A game with the given [Settings] whose piece sequence is entirely determined by `seed`.
+    pub fn with_settings_and_seed(settings: Settings, seed: u64) -> Self {
+        let mut next_queue = NextQueue::new(settings.randomizer.with_seed(seed));
+
+        let first = next_queue
+            .next()
+            .expect("Should be safe because [NextQueue::next] will never return [None]");
+
+        let falling_tetromino = first.new();
+
+        Tetris {
+            state: TetrisState::Ready {
+                remaining: settings.ready_duration,
+            },
+            matrix: Matrix::new(),
+            lock_delay: LockDelay::new(&falling_tetromino),
+            falling_tetromino,
+            auto_shift: AutoShift::new(),
+            next_queue,
+            seed,
+            hold_queue: HoldQueue::new(),
+            score: Score::new(),
+            streaks: Streaks::new(),
+            settings,
+            events: Vec::new(),
+        }
+        .spawn(first)
+    }
+
+    /// The events produced by the most recent call to [Tetris::update].
+    pub fn events(&self) -> &[TetrisEvent] {
+        &self.events
+    }
+
+    /// The rules this game is played by.
+    pub fn settings(&self) -> &Settings {
+        &self.settings
+    }
+
+    /// The seed the piece sequence was generated from.
+    pub fn seed(&self) -> u64 {
+        self.seed
+    }
+
+    /// The cells that have already been locked in place.
+    pub fn matrix(&self) -> &Matrix {
+        &self.matrix
+    }
+
+    /// The tetromino under the player's control, which is only shown while
+    /// [TetrisState::Playing] or [TetrisState::Paused].
+    pub fn falling_tetromino(&self) -> &Tetromino {
+        &self.falling_tetromino
+    }
+
+    /// Where the falling tetromino would land if it were hard dropped, or [None] when the ghost
+    /// piece is turned off.
+    pub fn ghost_tetromino(&self) -> Option<Tetromino> {
+        match self.settings.ghost_piece {
+            true => Some(
+                self.falling_tetromino
+                    .clone()
+                    .drop(self.matrix.drop_distance(&self.falling_tetromino)),
+            ),
+            false => None,
+        }
+    }
+
+    /// The tetrominoes that will fall after the current one.
+    pub fn next_queue(&self) -> &NextQueue<Box<dyn Randomizer>, 5> {
+        &self.next_queue
+    }
+
+    /// The held tetromino.
+    pub fn hold_queue(&self) -> &HoldQueue {
+        &self.hold_queue
+    }
+
+    /// The score, lines and level so far.
+    pub fn score(&self) -> &Score {
+        &self.score
+    }
+
+    /// What the game is doing right now.
+    pub fn state(&self) -> TetrisState {
+        self.state
+    }
+
+    /// Pauses a game in progress or resumes a paused one. Does nothing in any other state.
+    pub fn toggle_pause(mut self) -> Self {
+        self.state = match self.state {
+            TetrisState::Playing => TetrisState::Paused,
+            TetrisState::Paused => TetrisState::Playing,
+            state => state,
+        };
+
+        self
+    }
+
+    /// Advances the game by exactly one [TICK], applying every move made during it. Timers and
+    /// gravity only depend on how many ticks have passed, never on how fast they are rendered.
+    pub fn tick(self, actions: Moves) -> Self {
+        self.update(TICK, actions)
+    }
+
+    /// Advances the game by `delta_time`, applying every move made during that time. Prefer
+    /// [Tetris::tick], since a long `delta_time` applies its moves and gravity all at once.
+    pub fn update(mut self, delta_time: Duration, actions: Moves) -> Self {
+        self.events.clear();
+
+        let held_step = actions
+            .iter()
+            .rev()
+            .find_map(|tetris_move| match tetris_move {
+                TetrisMove::Shift(step) => Some(*step),
+                _ => None,
+            });
+
+        let shift_cells = self
+            .auto_shift
+            .charge(held_step, delta_time, &self.settings);
+
+        match self.state {
+            TetrisState::Ready { remaining } => {
+                self.state = match countdown(remaining, delta_time) {
+                    Some(remaining) => TetrisState::Ready { remaining },
+                    None => TetrisState::Playing,
+                };
+
+                self
+            }
+
+            TetrisState::Playing => self.play(delta_time, actions, shift_cells),
+
+            TetrisState::LineClearDelay { remaining } => match countdown(remaining, delta_time) {
+                Some(remaining) => {
+                    self.state = TetrisState::LineClearDelay { remaining };
+                    self
+                }
+                None => {
+                    let (matrix, _) = self.matrix.clear_lines();
+                    self.matrix = matrix;
+
+                    self.state = TetrisState::Playing;
+                    self.spawn_next()
+                }
+            },
+
+            TetrisState::Paused | TetrisState::GameOver(_) => self,
+        }
+    }
+
+    fn play(mut self, delta_time: Duration, actions: Moves, shift_cells: u8) -> Self {
+        let mut applied = Vec::with_capacity(actions.len());
+        let mut soft_dropping = false;
+
+        for tetris_move in actions {
+            if self.state != TetrisState::Playing {
+                return self;
+            }
+
+            // A move made more than once in a tick only counts once, like a key that is down.
+            if applied.contains(&tetris_move) {
+                continue;
+            }
+
+            applied.push(tetris_move.clone());
+
+            let previous_position = self.falling_tetromino.minoes();
+
+            match tetris_move {
+                TetrisMove::Rotate(rotation) => {
+                    if let Some(rotated) = self
+                        .falling_tetromino
+                        .rotate(rotation)
+                        .find(|kicked| self.matrix.validate(kicked) == TetrominoValidity::Valid)
+                    {
+                        self.falling_tetromino = rotated;
+                    }
+                }
+
+                TetrisMove::Shift(step) => {
+                    if Some(step) != self.auto_shift.step() {
+                        continue;
+                    }
+
+                    for _ in 0..shift_cells {
+                        let shifted = self.falling_tetromino.clone().shift(step);
+
+                        if self.matrix.validate(&shifted) == TetrominoValidity::Invalid {
+                            break;
+                        }
+
+                        self.falling_tetromino = shifted;
+                    }
+                }
+
+                TetrisMove::SoftDrop => soft_dropping = true,
+
+                TetrisMove::Hold => {
+                    if let Some(swapped) = self.hold_queue.hold(self.falling_tetromino.kind()) {
+                        let held = swapped.or_else(|| self.next_queue.next()).unwrap();
+                        self = self.spawn(held);
+                    }
+
+                    continue;
+                }
+
+                TetrisMove::HardDrop => {
+                    let rows = self.matrix.drop_distance(&self.falling_tetromino);
+
+                    self.falling_tetromino = self.falling_tetromino.drop(rows);
+                    self.score.hard_drop(rows);
+                    self.events.push(TetrisEvent::HardDrop { rows });
+
+                    self = self.lock();
+
+                    continue;
+                }
+            }
+
+            if self.falling_tetromino.minoes() != previous_position {
+                self.lock_delay.reset(self.settings.move_reset_limit);
+            }
+        }
+
+        if self.state != TetrisState::Playing {
+            return self;
+        }
+
+        let gravity = match soft_dropping {
+            true => self
+                .score
+                .gravity()
+                .saturating_mul(self.settings.soft_drop_factor),
+            false => self.score.gravity(),
+        };
+
+        let subrows = u128::from(gravity) * delta_time.as_nanos() * u128::from(TICKS_PER_SECOND)
+            / Duration::from_secs(1).as_nanos();
+
+        let (fallen, rows) = self.falling_tetromino.fall(subrows as u64);
+        let rows = rows.min(self.matrix.drop_distance(&fallen));
+
+        if soft_dropping {
+            self.score.soft_drop(rows);
+        }
+
+        self.falling_tetromino = fallen.drop(rows);
+
+        if self.falling_tetromino.lowest_row() < self.lock_delay.lowest_row {
+            self.lock_delay = LockDelay::new(&self.falling_tetromino);
+        }
+
+        if self.matrix.drop_distance(&self.falling_tetromino) == 0 {
+            self.lock_delay.elapsed += delta_time;
+
+            if self.lock_delay.elapsed >= self.settings.lock_delay
+                || self.lock_delay.resets >= self.settings.move_reset_limit
+            {
+                self = self.lock();
+            }
+        }
+
+        self
+    }
+
+    fn lock(mut self) -> Self {
+        let t_spin = self.matrix.t_spin(&self.falling_tetromino);
+
+        self.matrix = self.matrix.solidify(&self.falling_tetromino);
+        self.hold_queue.release();
+
+        let lines = self.matrix.filled_lines() as u8;
+        let perfect_clear = lines > 0 && self.matrix.clone().clear_lines().0.is_empty();
+
+        let result = self.streaks.lock(lines, t_spin, perfect_clear);
+
+        self.score.lock(&result);
+        self.events.push(TetrisEvent::Lock(result));
+
+        if self.falling_tetromino.lowest_row() >= PLAYFIELD_ROWS as i8 {
+            self.state = TetrisState::GameOver(TopOut::LockOut);
+            return self;
+        }
+
+        if lines > 0 {
+            self.state = TetrisState::LineClearDelay {
+                remaining: self.settings.line_clear_delay,
+            };
+            return self;
+        }
+
+        self.spawn_next()
+    }
+
+    fn spawn_next(mut self) -> Self {
+        let next = self.next_queue.next().unwrap();
+        self.spawn(next)
+    }
+
+    fn spawn(mut self, tetromino_kind: TetrominoKind) -> Self {
+        self.falling_tetromino = tetromino_kind.new();
+
+        if self.matrix.validate(&self.falling_tetromino) == TetrominoValidity::Invalid {
+            self.state = TetrisState::GameOver(TopOut::BlockOut);
+        } else if self.matrix.drop_distance(&self.falling_tetromino) > 0 {
+            self.falling_tetromino = self.falling_tetromino.drop(1);
+        }
+
+        self.lock_delay = LockDelay::new(&self.falling_tetromino);
+        self.events.push(TetrisEvent::Spawn(tetromino_kind));
+
+        self
+    }
+}
+
+/// Delayed auto shift: a direction shifts by one cell as soon as it is pressed, then starts
+/// repeating once it has been held for the DAS delay. The charge is kept while no tetromino is
+/// falling, so a held direction carries over to the next spawn.
+#[derive(Clone)]
+struct AutoShift {
+    charge: Option<(Step, Duration)>,
+}
+
+impl AutoShift {
+    fn new() -> Self {
+        AutoShift { charge: None }
+    }
+
+    fn step(&self) -> Option<Step> {
+        self.charge.map(|(step, _)| step)
+    }
+
+    /// Returns how many cells the held direction should shift by this frame.
+    fn charge(&mut self, held_step: Option<Step>, delta_time: Duration, settings: &Settings) -> u8 {
+        let repeats = |held: Duration| match held.checked_sub(settings.delayed_auto_shift) {
+            Some(charged) => charged.as_nanos() / settings.auto_repeat_rate.as_nanos() + 1,
+            None => 0,
+        };
+
+        match (held_step, self.charge) {
+            (None, _) => {
+                self.charge = None;
+                0
+            }
+            (Some(step), Some((charged_step, held))) if step == charged_step => {
+                let charged = held + delta_time;
+                self.charge = Some((step, charged));
+
+                if charged < settings.delayed_auto_shift {
+                    0
+                } else if settings.auto_repeat_rate.is_zero() {
+                    PLAYFIELD_COLUMNS as u8
+                } else {
+                    let repeats = repeats(charged).saturating_sub(repeats(held));
+                    repeats.min(PLAYFIELD_COLUMNS as u128) as u8
+                }
+            }
+            (Some(step), _) => {
+                self.charge = Some((step, Duration::ZERO));
+                1
+            }
+        }
+    }
+}
+
+/// Counts `remaining` down by `delta_time`, returning [None] once it has run out.
+fn countdown(remaining: Duration, delta_time: Duration) -> Option<Duration> {
+    match remaining.saturating_sub(delta_time) {
+        remaining if remaining.is_zero() => None,
+        remaining => Some(remaining),
+    }
+}
+
+/// Tracks how long the falling tetromino has been resting on the stack, and how many times
+/// moving it has bought it more time since it last reached a new lowest row.
+#[derive(Clone)]
+struct LockDelay {
+    elapsed: Duration,
+    resets: u8,
+    lowest_row: i8,
+}
+
+impl LockDelay {
+    fn new(tetromino: &Tetromino) -> Self {
+        LockDelay {
+            elapsed: Duration::ZERO,
+            resets: 0,
+            lowest_row: tetromino.lowest_row(),
+        }
+    }
+
+    fn reset(&mut self, move_reset_limit: u8) {
+        if self.elapsed > Duration::ZERO && self.resets < move_reset_limit {
+            self.elapsed = Duration::ZERO;
+            self.resets += 1;
+        }
+    }
+}
+
+impl TetrisState {
+    /// The lines shown over the playfield, if any.
+    pub fn message(&self) -> &'static [&'static str] {
+        match self {
+            TetrisState::Ready { .. } => &["READY"],
+            TetrisState::Paused => &["PAUSED", "Press P to resume"],
+            TetrisState::GameOver(TopOut::BlockOut) => &["BLOCK OUT", "Press Enter to restart"],
+            TetrisState::GameOver(TopOut::LockOut) => &["LOCK OUT", "Press Enter to restart"],
+            TetrisState::Playing | TetrisState::LineClearDelay { .. } => &[],
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::super::matrix::{Cell, TSpin};
+    use super::super::tetromino::{Position, Rotation, RotationState};
+    use super::*;
+
+    /// Builds a matrix from rows drawn top to bottom, where `#` is a filled cell and the last
+    /// row is the bottom of the matrix.
+    fn matrix(rows: &str) -> Matrix {
+        rows.lines()
+            .map(str::trim)
+            .filter(|line| !line.is_empty())
+            .rev()
+            .enumerate()
+            .flat_map(|(row, line)| {
+                line.chars()
+                    .enumerate()
+                    .filter(|&(_, cell)| cell == '#')
+                    .map(move |(column, _)| Position {
+                        row: row as i8,
+                        column: column as i8,
+                    })
+            })
+            .fold(Matrix::new(), |matrix, position| {
+                matrix.with_cell(&position, Cell::Garbage)
+            })
+    }
+
+    /// An I standing up in the rightmost column, on the floor.
+    fn upright_i() -> Tetromino {
+        TetrominoKind::I.placed(RotationState::Left, Position { row: 0, column: 8 })
+    }
+
+    /// Four rows with only the rightmost column left open, under a cell that keeps a tetris
+    /// from being a perfect clear.
+    const TETRIS_WELL: &str = "
+        #.........
+        #########.
+        #########.
+        #########.
+        #########.
+    ";
+
+    /// [TETRIS_WELL] without anything left over once it is cleared.
+    const PERFECT_CLEAR_WELL: &str = "
+        #########.
+        #########.
+        #########.
+        #########.
+    ";
+
+    /// Locks `tetromino` on `rows` after making `moves`, keeping the score and streaks of the
+    /// locks before it. Returns what the lock scored and the points it was worth.
+    fn lock(
+        tetris: &mut Tetris,
+        rows: &str,
+        tetromino: Tetromino,
+        moves: Moves,
+    ) -> (LockResult, u64) {
+        let points = tetris.score().points();
+
+        tetris.state = TetrisState::Playing;
+        tetris.matrix = matrix(rows);
+        tetris.falling_tetromino = tetromino;
+
+        let mut moves = moves;
+        moves.push(TetrisMove::HardDrop);
+
+        *tetris = tetris.clone().tick(moves);
+
+        let result = tetris
+            .events()
+            .iter()
+            .find_map(|event| match event {
+                TetrisEvent::Lock(result) => Some(*result),
+                _ => None,
+            })
+            .expect("Should be safe because a hard drop always locks");
+
+        (result, tetris.score().points() - points)
+    }
+
+    #[test]
+    fn back_to_back_is_broken_by_a_single() {
+        let mut tetris = Tetris::with_seed(0);
+
+        let (first, points) = lock(&mut tetris, TETRIS_WELL, upright_i(), vec![]);
+        assert!(!first.back_to_back);
+        assert_eq!(points, 800);
+
+        // Locking without clearing anything keeps the chain going, but ends the combo.
+        let (_, points) = lock(&mut tetris, "", upright_i(), vec![]);
+        assert_eq!(points, 0);
+
+        let (second, points) = lock(&mut tetris, TETRIS_WELL, upright_i(), vec![]);
+        assert!(second.back_to_back);
+        assert_eq!(second.combo, Some(0));
+        assert_eq!(points, 1200);
+
+        let (single, points) = lock(&mut tetris, "#########.", upright_i(), vec![]);
+        assert!(!single.back_to_back);
+        assert_eq!(single.combo, Some(1));
+        assert_eq!(points, 100 + 50);
+
+        let (third, points) = lock(&mut tetris, TETRIS_WELL, upright_i(), vec![]);
+        assert!(!third.back_to_back);
+        assert_eq!(third.combo, Some(2));
+        assert_eq!(points, 800 + 100);
+    }
+
+    #[test]
+    fn t_spin_double_keeps_back_to_back() {
+        let mut tetris = Tetris::with_seed(0);
+
+        lock(&mut tetris, TETRIS_WELL, upright_i(), vec![]);
+
+        let (t_spin_double, points) = lock(
+            &mut tetris,
+            "
+            ####......
+            ###...####
+            ####.#####
+            ",
+            TetrominoKind::T.placed(RotationState::Right, Position { row: 0, column: 3 }),
+            vec![TetrisMove::Rotate(Rotation::Clockwise)],
+        );
+
+        assert_eq!(t_spin_double.t_spin, TSpin::Full);
+        assert_eq!(t_spin_double.lines, 2);
+        assert!(t_spin_double.back_to_back);
+        assert_eq!(points, 1200 * 3 / 2 + 50);
+    }
+
+    #[test]
+    fn perfect_clears() {
+        let mut tetris = Tetris::with_seed(0);
+
+        let (single, points) = lock(
+            &mut tetris,
+            "######....",
+            TetrominoKind::I.placed(RotationState::Spawn, Position { row: -2, column: 6 }),
+            vec![],
+        );
+        assert!(single.perfect_clear);
+        assert_eq!(points, 100 + 800);
+
+        let (tetris_clear, points) = lock(&mut tetris, PERFECT_CLEAR_WELL, upright_i(), vec![]);
+        assert!(tetris_clear.perfect_clear);
+        assert!(!tetris_clear.back_to_back);
+        assert_eq!(points, 800 + 50 + 2000);
+
+        let (back_to_back, points) = lock(&mut tetris, PERFECT_CLEAR_WELL, upright_i(), vec![]);
+        assert!(back_to_back.perfect_clear);
+        assert!(back_to_back.back_to_back);
+        assert_eq!(points, 1200 + 100 + 3200);
+    }
+
+    #[test]
+    fn leftovers_are_not_a_perfect_clear() {
+        let mut tetris = Tetris::with_seed(0);
+
+        let (single, points) = lock(&mut tetris, "#########.", upright_i(), vec![]);
+
+        assert!(!single.perfect_clear);
+        assert_eq!(points, 100);
+    }
+}
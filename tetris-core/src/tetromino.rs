@@ -0,0 +1,344 @@
+/// Fractions of a row held in the gravity accumulator, so that gravity slower than a row per
+/// frame can be applied without any floating point drift.
+pub const SUBROWS_PER_ROW: u32 = 1 << 16;
+
+/// A tetromino placed in the matrix.
+#[derive(Debug, Clone)]
+pub struct Tetromino {
+    origin: Position,
+    kind: TetrominoKind,
+    rotation_state: RotationState,
+    last_move: LastMove,
+    gravity: u32,
+}
+
+impl Tetromino {
+    /// Yields the rotated tetromino once for every SRS kick offset, in the order the offsets
+    /// should be tested against the matrix.
+    pub fn rotate(&self, rotation: Rotation) -> impl Iterator<Item = Self> {
+        let kicks = self.kind.kicks(self.rotation_state, rotation);
+
+        let mut rotated = self.clone();
+        rotated.rotation_state = rotated.rotation_state.rotate(rotation);
+
+        kicks
+            .iter()
+            .enumerate()
+            .map(move |(kick, &(x_offset, y_offset))| {
+                let mut kicked = rotated.clone();
+
+                kicked.origin.column += x_offset;
+                kicked.origin.row += y_offset;
+                kicked.last_move = LastMove::Rotation { kick: kick as u8 };
+
+                kicked
+            })
+    }
+
+    /// Adds `subrows` to the gravity accumulator and returns how many whole rows the tetromino
+    /// should now fall, keeping the remainder for later.
+    pub(crate) fn fall(mut self, subrows: u64) -> (Self, u8) {
+        let subrows = u64::from(self.gravity) + subrows;
+        let rows = subrows / u64::from(SUBROWS_PER_ROW);
+
+        self.gravity = (subrows % u64::from(SUBROWS_PER_ROW)) as u32;
+
+        (self, rows.min(u64::from(u8::MAX)) as u8)
+    }
+
+    /// Moves the tetromino `rows` down, whether or not there is room for it.
+    pub(crate) fn drop(mut self, rows: u8) -> Self {
+        self.origin.row -= rows as i8;
+
+        if rows > 0 {
+            self.last_move = LastMove::Drop;
+        }
+
+        self
+    }
+
+    /// Moves the tetromino a column over, whether or not there is room for it.
+    pub(crate) fn shift(mut self, step: Step) -> Self {
+        self.origin.column += step.x_axis_step();
+        self.last_move = LastMove::Shift;
+
+        self
+    }
+
+    /// The last move that actually moved the tetromino.
+    pub fn last_move(&self) -> LastMove {
+        self.last_move
+    }
+
+    /// The cells diagonal to the center of the tetromino, split into the two corners its point
+    /// faces and the two behind it. Only meaningful for [TetrominoKind::T].
+    pub fn corners(&self) -> ([Position; 2], [Position; 2]) {
+        use RotationState::*;
+
+        let corner = |x_offset: i8, y_offset: i8| Position {
+            row: self.origin.row + 1 + y_offset,
+            column: self.origin.column + 1 + x_offset,
+        };
+
+        match self.rotation_state {
+            Spawn => (
+                [corner(-1, 1), corner(1, 1)],
+                [corner(-1, -1), corner(1, -1)],
+            ),
+            Right => (
+                [corner(1, 1), corner(1, -1)],
+                [corner(-1, 1), corner(-1, -1)],
+            ),
+            Reverse => (
+                [corner(-1, -1), corner(1, -1)],
+                [corner(-1, 1), corner(1, 1)],
+            ),
+            Left => (
+                [corner(-1, 1), corner(-1, -1)],
+                [corner(1, 1), corner(1, -1)],
+            ),
+        }
+    }
+
+    /// The row of the lowest mino.
+    pub fn lowest_row(&self) -> i8 {
+        self.minoes()
+            .iter()
+            .map(|position| position.row)
+            .min()
+            .expect("Should be safe because a [Tetromino] always has four minoes")
+    }
+
+    /// Which of the seven tetrominoes this is.
+    pub fn kind(&self) -> TetrominoKind {
+        self.kind
+    }
+
+    /// How the tetromino is rotated.
+    pub fn rotation_state(&self) -> RotationState {
+        self.rotation_state
+    }
+
+    /// Where each of the four minoes are in the matrix.
+    pub fn minoes(&self) -> [Position; 4] {
+        self.kind
+            .minoes(self.rotation_state)
+            .map(|(x_offset, y_offset)| Position {
+                row: self.origin.row + y_offset,
+                column: self.origin.column + x_offset,
+            })
+    }
+}
+
+use strum::{EnumCount, EnumIter};
+
+/// The seven tetrominoes, named after the letters they look like.
+#[derive(EnumCount, EnumIter, Debug, Clone, Copy, Eq, Hash, PartialEq)]
+pub enum TetrominoKind {
+    /// The square.
+    O,
+    /// The straight line.
+    I,
+    /// The T, the only one that can T-spin.
+    T,
+    /// The L, with its foot on the right.
+    L,
+    /// The J, with its foot on the left.
+    J,
+    /// The S, rising to the right.
+    S,
+    /// The Z, falling to the right.
+    Z,
+}
+
+/// An offset as `(x, y)`, where positive `y` points up the matrix.
+pub type Offset = (i8, i8);
+
+impl TetrominoKind {
+    fn kicks(&self, from: RotationState, rotation: Rotation) -> &'static [Offset] {
+        use Rotation::*;
+        use RotationState::*;
+
+        match self {
+            TetrominoKind::O => &[(0, 0)],
+            TetrominoKind::I => match (from, rotation) {
+                (Spawn, Clockwise) | (Left, Counterclockwise) => {
+                    &[(0, 0), (-2, 0), (1, 0), (-2, -1), (1, 2)]
+                }
+                (Right, Counterclockwise) | (Reverse, Clockwise) => {
+                    &[(0, 0), (2, 0), (-1, 0), (2, 1), (-1, -2)]
+                }
+                (Right, Clockwise) | (Spawn, Counterclockwise) => {
+                    &[(0, 0), (-1, 0), (2, 0), (-1, 2), (2, -1)]
+                }
+                (Reverse, Counterclockwise) | (Left, Clockwise) => {
+                    &[(0, 0), (1, 0), (-2, 0), (1, -2), (-2, 1)]
+                }
+            },
+            _ => match (from, rotation) {
+                (Spawn, Clockwise) | (Reverse, Counterclockwise) => {
+                    &[(0, 0), (-1, 0), (-1, 1), (0, -2), (-1, -2)]
+                }
+                (Right, Counterclockwise) | (Right, Clockwise) => {
+                    &[(0, 0), (1, 0), (1, -1), (0, 2), (1, 2)]
+                }
+                (Reverse, Clockwise) | (Spawn, Counterclockwise) => {
+                    &[(0, 0), (1, 0), (1, 1), (0, -2), (1, -2)]
+                }
+                (Left, Counterclockwise) | (Left, Clockwise) => {
+                    &[(0, 0), (-1, 0), (-1, -1), (0, 2), (-1, 2)]
+                }
+            },
+        }
+    }
+
+    /// Where each mino sits inside the bounding box of the tetromino in every rotation state,
+    /// counted from the bottom left corner of the box.
+    pub fn minoes(&self, rotation_state: RotationState) -> [Offset; 4] {
+        use RotationState::*;
+        use TetrominoKind::*;
+
+        match (self, rotation_state) {
+            (O, _) => [(0, 1), (1, 1), (0, 0), (1, 0)],
+
+            (I, Spawn) => [(0, 2), (1, 2), (2, 2), (3, 2)],
+            (I, Right) => [(2, 3), (2, 2), (2, 1), (2, 0)],
+            (I, Reverse) => [(0, 1), (1, 1), (2, 1), (3, 1)],
+            (I, Left) => [(1, 3), (1, 2), (1, 1), (1, 0)],
+
+            (T, Spawn) => [(1, 2), (0, 1), (1, 1), (2, 1)],
+            (T, Right) => [(1, 2), (1, 1), (2, 1), (1, 0)],
+            (T, Reverse) => [(0, 1), (1, 1), (2, 1), (1, 0)],
+            (T, Left) => [(1, 2), (0, 1), (1, 1), (1, 0)],
+
+            (L, Spawn) => [(2, 2), (0, 1), (1, 1), (2, 1)],
+            (L, Right) => [(1, 2), (1, 1), (1, 0), (2, 0)],
+            (L, Reverse) => [(0, 1), (1, 1), (2, 1), (0, 0)],
+            (L, Left) => [(0, 2), (1, 2), (1, 1), (1, 0)],
+
+            (J, Spawn) => [(0, 2), (0, 1), (1, 1), (2, 1)],
+            (J, Right) => [(1, 2), (2, 2), (1, 1), (1, 0)],
+            (J, Reverse) => [(0, 1), (1, 1), (2, 1), (2, 0)],
+            (J, Left) => [(1, 2), (1, 1), (0, 0), (1, 0)],
+
+            (S, Spawn) => [(1, 2), (2, 2), (0, 1), (1, 1)],
+            (S, Right) => [(1, 2), (1, 1), (2, 1), (2, 0)],
+            (S, Reverse) => [(1, 1), (2, 1), (0, 0), (1, 0)],
+            (S, Left) => [(0, 2), (0, 1), (1, 1), (1, 0)],
+
+            (Z, Spawn) => [(0, 2), (1, 2), (1, 1), (2, 1)],
+            (Z, Right) => [(2, 2), (1, 1), (2, 1), (1, 0)],
+            (Z, Reverse) => [(0, 1), (1, 1), (1, 0), (2, 0)],
+            (Z, Left) => [(1, 2), (0, 1), (1, 1), (0, 0)],
+        }
+    }
+
+    /// A tetromino in `rotation_state` whose bounding box has its bottom left corner at
+    /// `origin`, for placing one somewhere other than where it spawns.
+    pub fn placed(&self, rotation_state: RotationState, origin: Position) -> Tetromino {
+        Tetromino {
+            origin,
+            kind: *self,
+            rotation_state,
+            last_move: LastMove::Spawn,
+            gravity: 0,
+        }
+    }
+
+    /// The tetromino as it spawns at the top of the matrix.
+    #[allow(clippy::new_ret_no_self, clippy::wrong_self_convention)]
+    pub fn new(&self) -> Tetromino {
+        use TetrominoKind::*;
+
+        Tetromino {
+            origin: match self {
+                O => Position { row: 20, column: 4 },
+                I => Position { row: 18, column: 3 },
+                T | L | J | S | Z => Position { row: 19, column: 3 },
+            },
+            kind: *self,
+            rotation_state: RotationState::Spawn,
+            last_move: LastMove::Spawn,
+            gravity: 0,
+        }
+    }
+}
+
+/// A cell of the matrix.
+#[derive(Debug, PartialEq, Eq, Clone, Copy)]
+pub struct Position {
+    /// Counted up from the bottom row, which is 0.
+    pub row: i8,
+    /// Counted from the left column, which is 0.
+    pub column: i8,
+}
+
+/// A direction to rotate a tetromino in.
+#[derive(Debug, PartialEq, Eq, Clone, Copy)]
+pub enum Rotation {
+    /// Turns right.
+    Clockwise,
+    /// Turns left.
+    Counterclockwise,
+}
+
+/// The last action that actually moved a tetromino, used to tell T-spins apart from pieces
+/// that were simply dropped into place.
+#[derive(Debug, PartialEq, Eq, Clone, Copy)]
+pub enum LastMove {
+    /// The tetromino hasn't moved since it spawned.
+    Spawn,
+    /// Moved a column over.
+    Shift,
+    /// Fell or was dropped.
+    Drop,
+    /// Rotated.
+    Rotation {
+        /// Which of the SRS kick offsets it took, 0 being no kick at all.
+        kick: u8,
+    },
+}
+
+/// The four SRS orientations, commonly written as 0, R, 2 and L.
+#[derive(Debug, PartialEq, Eq, Clone, Copy)]
+pub enum RotationState {
+    /// As it spawns, 0.
+    Spawn,
+    /// Turned clockwise once, R.
+    Right,
+    /// Turned upside down, 2.
+    Reverse,
+    /// Turned counterclockwise once, L.
+    Left,
+}
+
+impl RotationState {
+    fn rotate(self, rotation: Rotation) -> Self {
+        use RotationState::*;
+
+        match (self, rotation) {
+            (Spawn, Rotation::Clockwise) | (Reverse, Rotation::Counterclockwise) => Right,
+            (Right, Rotation::Clockwise) | (Left, Rotation::Counterclockwise) => Reverse,
+            (Reverse, Rotation::Clockwise) | (Spawn, Rotation::Counterclockwise) => Left,
+            (Left, Rotation::Clockwise) | (Right, Rotation::Counterclockwise) => Spawn,
+        }
+    }
+}
+
+/// A direction to shift a tetromino in.
+#[derive(Debug, PartialEq, Eq, Clone, Copy)]
+pub enum Step {
+    /// Towards column 0.
+    Left,
+    /// Away from column 0.
+    Right,
+}
+
+impl Step {
+    fn x_axis_step(&self) -> i8 {
+        match self {
+            Step::Left => -1,
+            Step::Right => 1,
+        }
+    }
+}
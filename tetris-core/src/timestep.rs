@@ -0,0 +1,57 @@
+use super::player::{Moves, TetrisMove};
+use super::tetris::TICK;
+
+use std::time::Duration;
+
+/// Frames longer than this are cut short, so that a hitch or a breakpoint does not make the
+/// simulation run hundreds of ticks at once to catch up.
+const MAX_FRAME_TIME: Duration = Duration::from_millis(250);
+
+/// Turns the variable time between rendered frames into a whole number of [TICK]s, carrying
+/// the remainder over to the next frame.
+pub struct FixedTimestep {
+    accumulator: Duration,
+    pending: Moves,
+}
+
+impl Default for FixedTimestep {
+    fn default() -> Self {
+        FixedTimestep::new()
+    }
+}
+
+impl FixedTimestep {
+    /// A timestep with no time left over.
+    pub fn new() -> Self {
+        FixedTimestep {
+            accumulator: Duration::ZERO,
+            pending: Vec::new(),
+        }
+    }
+
+    /// Adds `frame_time` to the accumulator and returns the moves for every tick that is now
+    /// due. Held moves are repeated on each of those ticks, while pressed moves only happen on
+    /// the first one, or on the next frame that has a tick at all.
+    pub fn advance(&mut self, frame_time: Duration, moves: Moves) -> Vec<Moves> {
+        let (held, pressed): (Moves, Moves) = moves.into_iter().partition(TetrisMove::is_held);
+
+        self.pending.extend(pressed);
+        self.accumulator += frame_time.min(MAX_FRAME_TIME);
+
+        let mut ticks = Vec::new();
+
+        while self.accumulator >= TICK {
+            self.accumulator -= TICK;
+
+            ticks.push(held.iter().cloned().chain(self.pending.drain(..)).collect());
+        }
+
+        ticks
+    }
+
+    /// How far the accumulator is into the next tick, from 0 to 1, for interpolating what is
+    /// drawn between the last two ticks.
+    pub fn alpha(&self) -> f32 {
+        self.accumulator.as_secs_f32() / TICK.as_secs_f32()
+    }
+}
@@ -0,0 +1,155 @@
+use tetris_core::fumen::{decode, encode, FumenError, Page};
+use tetris_core::matrix::{Cell, Matrix};
+use tetris_core::tetromino::{Position, RotationState};
+use tetris_core::TetrominoKind;
+
+fn position(column: i8, row: i8) -> Position {
+    Position { row, column }
+}
+
+/// The positions in the bottom `rows` rows of `matrix` holding `cell`.
+fn cells(matrix: &Matrix, rows: i8, cell: Cell) -> Vec<Position> {
+    (0..rows)
+        .flat_map(|row| (0..10).map(move |column| position(column, row)))
+        .filter(|position| matrix.cell(position) == Some(cell))
+        .collect()
+}
+
+/// Decodes `fumen` and checks that encoding the pages gives back the same string.
+fn round_trip(fumen: &str) -> Vec<Page> {
+    let pages = decode(fumen).expect("Should be safe because the fumen is valid");
+
+    assert_eq!(
+        encode(&pages).expect("Should be safe because the pages were just decoded"),
+        fumen
+    );
+
+    pages
+}
+
+#[test]
+fn empty_page() {
+    let pages = round_trip("v115@vhAAgH");
+
+    assert_eq!(pages.len(), 1);
+    assert!(pages[0].matrix.is_empty());
+    assert!(pages[0].tetromino.is_none());
+    assert!(pages[0].comment.is_empty());
+    assert!(pages[0].lock);
+}
+
+#[test]
+fn comment() {
+    let pages = round_trip("v115@vhAAgWBABBAAA");
+
+    assert_eq!(pages.len(), 1);
+    assert_eq!(pages[0].comment, "a");
+    assert!(pages[0].lock);
+    assert!(!pages[0].rise);
+    assert!(!pages[0].mirror);
+}
+
+#[test]
+fn escaped_comment_with_line_breaks() {
+    let fumen = "v115@vhAAgWkAQIKvDll2TASI3CElCyTAyp78AQu78AQC2J?ElN98AwXrVB";
+    let pages = round_trip(fumen);
+
+    assert_eq!(fumen.find('?'), Some("v115@".len() + 42));
+    assert_eq!(pages[0].comment, "Perfect clear! 4 lines é");
+}
+
+#[test]
+fn comment_carries_over_repeated_pages() {
+    let pages = round_trip("v115@vhBAgWBABBAAAAAA");
+
+    assert_eq!(pages.len(), 2);
+    assert!(pages.iter().all(|page| page.matrix.is_empty()));
+    assert!(pages.iter().all(|page| page.comment == "a"));
+}
+
+#[test]
+fn locked_tetromino() {
+    let pages = round_trip("v115@vhBVQJAAA");
+    let tetromino = pages[0]
+        .tetromino
+        .as_ref()
+        .expect("Should be safe because the first page places a T");
+
+    assert_eq!(tetromino.kind(), TetrominoKind::T);
+    assert_eq!(tetromino.rotation_state(), RotationState::Spawn);
+    assert!(pages[0].lock);
+
+    assert_eq!(
+        cells(&pages[1].matrix, 2, Cell::Filled(TetrominoKind::T)),
+        [
+            position(3, 0),
+            position(4, 0),
+            position(5, 0),
+            position(4, 1)
+        ]
+    );
+    assert!(pages[1].tetromino.is_none());
+}
+
+#[test]
+fn unlocked_tetromino() {
+    let pages = round_trip("v115@vhBVQnAAA");
+
+    assert_eq!(pages.len(), 2);
+    assert!(!pages[0].lock);
+    assert!(pages[0].tetromino.is_some());
+    assert!(pages[1].matrix.is_empty());
+}
+
+#[test]
+fn rise() {
+    let pages = round_trip("v115@lhI8AeAYJvhAAAA");
+    let row = (0..9).map(|column| position(column, 0)).collect::<Vec<_>>();
+
+    assert!(pages[0].rise);
+    assert!(pages[0].matrix.is_empty());
+    assert_eq!(pages[0].garbage[..9], [Cell::Garbage; 9]);
+    assert_eq!(pages[0].garbage[9], Cell::Empty);
+
+    assert_eq!(cells(&pages[1].matrix, 1, Cell::Garbage), row);
+    assert_eq!(pages[1].garbage, [Cell::Empty; 10]);
+}
+
+#[test]
+fn mirror() {
+    let pages = round_trip("v115@bhC8QeAQLvhAAAA");
+
+    assert!(pages[0].mirror);
+    assert_eq!(
+        cells(&pages[0].matrix, 1, Cell::Garbage),
+        [position(0, 0), position(1, 0), position(2, 0)]
+    );
+    assert_eq!(
+        cells(&pages[1].matrix, 1, Cell::Garbage),
+        [position(7, 0), position(8, 0), position(9, 0)]
+    );
+}
+
+#[test]
+fn stacked_field() {
+    let pages = round_trip("v115@9gF8DeF8DeF8DeF8NeAgH");
+    let stack = (0..4)
+        .flat_map(|row| (0..6).map(move |column| position(column, row)))
+        .collect::<Vec<_>>();
+
+    assert_eq!(pages.len(), 1);
+    assert_eq!(cells(&pages[0].matrix, 5, Cell::Garbage), stack);
+}
+
+#[test]
+fn rejects_other_versions() {
+    assert_eq!(
+        decode("v110@7eAA4G").err(),
+        Some(FumenError::UnsupportedVersion)
+    );
+}
+
+#[test]
+fn rejects_truncated_pages() {
+    assert_eq!(decode("v115@vhAAg").err(), Some(FumenError::UnexpectedEnd));
+}
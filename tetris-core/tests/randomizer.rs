@@ -0,0 +1,132 @@
+use tetris_core::next_queue::NextQueue;
+use tetris_core::randomizer::{FourteenBag, Randomizer, SevenBag, Tgm3};
+use tetris_core::{RandomizerKind, Tetris, TetrominoKind};
+
+fn deal(kind: RandomizerKind, seed: u64) -> Vec<TetrominoKind> {
+    kind.with_seed(seed).take(14).collect()
+}
+
+#[test]
+fn seeds_deal_fixed_sequences() {
+    use RandomizerKind::*;
+    use TetrominoKind::*;
+
+    let sequences = [
+        (SevenBag, [I, T, Z, L, O, S, J, I, Z, L, J, O, S, T]),
+        (FourteenBag, [S, O, J, L, S, I, O, Z, J, Z, T, I, L, T]),
+        (Memoryless, [J, L, L, T, L, T, I, I, J, Z, Z, J, O, J]),
+        (Nes, [Z, I, L, L, T, J, J, S, I, O, Z, I, L, T]),
+        (Tgm, [L, T, I, J, Z, O, L, T, J, S, J, Z, T, I]),
+        (Tgm2, [L, T, I, J, Z, O, L, T, J, S, Z, I, L, T]),
+        (Tgm3, [L, J, T, O, S, I, J, L, O, T, Z, J, L, S]),
+    ];
+
+    for (kind, sequence) in sequences {
+        assert_eq!(deal(kind, 42), sequence, "{kind:?}");
+    }
+}
+
+#[test]
+fn games_deal_from_their_seed() {
+    let tetris = Tetris::with_seed(42);
+    let sequence = deal(RandomizerKind::SevenBag, 42);
+
+    assert_eq!(tetris.falling_tetromino().kind(), sequence[0]);
+    assert!(tetris.next_queue().upcoming().eq(&sequence[1..6]));
+}
+
+const KINDS: [TetrominoKind; 7] = [
+    TetrominoKind::I,
+    TetrominoKind::J,
+    TetrominoKind::L,
+    TetrominoKind::O,
+    TetrominoKind::S,
+    TetrominoKind::T,
+    TetrominoKind::Z,
+];
+
+fn count(pieces: &[TetrominoKind], kind: TetrominoKind) -> usize {
+    pieces.iter().filter(|&&piece| piece == kind).count()
+}
+
+#[test]
+fn bags_hold_every_tetromino() {
+    for seed in 0..100 {
+        let seven_bags: Vec<_> = SevenBag::with_seed(seed).take(7 * 10).collect();
+        let fourteen_bags: Vec<_> = FourteenBag::with_seed(seed).take(14 * 10).collect();
+
+        for bag in seven_bags.chunks(7) {
+            assert!(KINDS.iter().all(|&kind| count(bag, kind) == 1), "{bag:?}");
+        }
+
+        for bag in fourteen_bags.chunks(14) {
+            assert!(KINDS.iter().all(|&kind| count(bag, kind) == 2), "{bag:?}");
+        }
+    }
+}
+
+#[test]
+fn tgm_never_opens_with_an_overhang() {
+    use TetrominoKind::{I, J, L, T};
+
+    for seed in 0..1000 {
+        for kind in [
+            RandomizerKind::Tgm,
+            RandomizerKind::Tgm2,
+            RandomizerKind::Tgm3,
+        ] {
+            let first = kind.with_seed(seed).next();
+
+            assert!(matches!(first, Some(I | J | L | T)), "{kind:?} {first:?}");
+        }
+    }
+}
+
+#[test]
+fn tgm3_refills_its_pool_with_the_most_droughted_tetromino() {
+    let mut tgm3 = Tgm3::with_seed(42);
+    let mut dealt: Vec<TetrominoKind> = Vec::new();
+
+    // The first piece is picked without touching the pool or the droughts.
+    tgm3.next();
+
+    for _ in 0..1000 {
+        let pool = tgm3.pool().to_vec();
+        let most_droughted = tgm3.most_droughted();
+
+        let piece = tgm3
+            .next()
+            .expect("Should be safe because [Tgm3] is endless");
+
+        dealt.retain(|&kind| kind != piece);
+        dealt.push(piece);
+
+        assert_eq!(tgm3.most_droughted(), dealt.first().copied());
+        assert_eq!(tgm3.pool().len(), 35);
+
+        // Rerolled pieces go back as the most droughted tetromino from before the deal, and the
+        // dealt one as the most droughted tetromino from after it.
+        for (&before, &after) in pool.iter().zip(tgm3.pool()) {
+            assert!(
+                before == after
+                    || Some(after) == most_droughted
+                    || Some(after) == tgm3.most_droughted()
+            );
+        }
+    }
+}
+
+#[test]
+fn next_queues_deal_in_order() {
+    fn dealt<const SIZE: usize>() -> Vec<TetrominoKind> {
+        NextQueue::<SevenBag, SIZE>::with_seed(42)
+            .take(700)
+            .collect()
+    }
+
+    let sequence: Vec<_> = SevenBag::with_seed(42).take(700).collect();
+
+    assert_eq!(dealt::<1>(), sequence);
+    assert_eq!(dealt::<5>(), sequence);
+    assert_eq!(dealt::<300>(), sequence);
+}
@@ -0,0 +1,139 @@
+use std::io::ErrorKind;
+
+use tetris_core::{Recording, Replay, Rotation, Settings, Step, TetrisMove};
+
+/// A short recording with runs of held moves, single presses and idle ticks in between.
+fn recording() -> Recording {
+    let mut recording = Recording::new(42, Settings::default());
+
+    let ticks = [
+        vec![],
+        vec![TetrisMove::Shift(Step::Left)],
+        vec![TetrisMove::Shift(Step::Left)],
+        vec![TetrisMove::Shift(Step::Left), TetrisMove::SoftDrop],
+        vec![],
+        vec![],
+        vec![TetrisMove::Rotate(Rotation::Counterclockwise)],
+        vec![TetrisMove::HardDrop],
+        vec![TetrisMove::Hold],
+        vec![],
+    ];
+
+    for moves in &ticks {
+        recording.record(moves);
+    }
+
+    recording
+}
+
+fn error_kind(bytes: &[u8]) -> ErrorKind {
+    Recording::decode(bytes)
+        .expect_err("Should reject a malformed replay")
+        .kind()
+}
+
+#[test]
+fn round_trip() {
+    let recording = recording();
+    let decoded = Recording::decode(&recording.encode()).expect("Should decode its own encoding");
+
+    assert_eq!(decoded, recording);
+    assert_eq!(
+        Replay::new(decoded).collect::<Vec<_>>(),
+        Replay::new(recording).collect::<Vec<_>>()
+    );
+}
+
+#[test]
+fn rejects_bad_magic_number() {
+    let mut bytes = recording().encode();
+    bytes[0] = b'X';
+
+    assert_eq!(error_kind(&bytes), ErrorKind::InvalidData);
+}
+
+#[test]
+fn rejects_unknown_version() {
+    let mut bytes = recording().encode();
+    bytes[4] += 1;
+
+    assert_eq!(error_kind(&bytes), ErrorKind::InvalidData);
+}
+
+#[test]
+fn rejects_trailing_bytes() {
+    let mut bytes = recording().encode();
+    bytes.push(0);
+
+    assert_eq!(error_kind(&bytes), ErrorKind::InvalidData);
+}
+
+#[test]
+fn rejects_truncated_replay() {
+    let bytes = recording().encode();
+
+    assert_eq!(
+        error_kind(&bytes[..bytes.len() - 1]),
+        ErrorKind::UnexpectedEof
+    );
+}
+
+#[test]
+fn rejects_run_past_ticks() {
+    // Without any runs, a recording ends with its tick count and a run count of zero, one byte
+    // each, which gives away where the tick count of the full recording is.
+    let ticks = Recording::new(42, Settings::default()).encode().len() - 2;
+
+    let mut bytes = recording().encode();
+    assert_eq!(bytes[ticks], 10);
+    bytes[ticks] = 5;
+
+    assert_eq!(error_kind(&bytes), ErrorKind::InvalidData);
+}
+
+#[test]
+fn records_repeated_moves_once() {
+    let mut recording = Recording::new(42, Settings::default());
+    recording.record(&vec![
+        TetrisMove::Hold,
+        TetrisMove::SoftDrop,
+        TetrisMove::Hold,
+    ]);
+
+    let decoded = Recording::decode(&recording.encode()).expect("Should decode its own encoding");
+
+    assert_eq!(
+        Replay::new(decoded).collect::<Vec<_>>(),
+        [vec![TetrisMove::Hold, TetrisMove::SoftDrop]]
+    );
+}
+
+#[test]
+fn rejects_repeated_moves() {
+    let mut recording = Recording::new(42, Settings::default());
+    recording.record(&vec![
+        TetrisMove::Rotate(Rotation::Clockwise),
+        TetrisMove::Rotate(Rotation::Counterclockwise),
+    ]);
+
+    // The moves of the last run come last, so this turns the counterclockwise rotation into a
+    // second clockwise one.
+    let mut bytes = recording.encode();
+    *bytes.last_mut().unwrap() = bytes[bytes.len() - 2];
+
+    assert_eq!(error_kind(&bytes), ErrorKind::InvalidData);
+}
+
+#[test]
+fn rejects_soft_drop_factors_out_of_range() {
+    for soft_drop_factor in [0, u32::MAX] {
+        let settings = Settings {
+            soft_drop_factor,
+            ..Settings::default()
+        };
+
+        let bytes = Recording::new(42, settings).encode();
+
+        assert_eq!(error_kind(&bytes), ErrorKind::InvalidData);
+    }
+}
@@ -0,0 +1,116 @@
+use tetris_core::matrix::{Cell, Matrix, TSpin, TetrominoValidity};
+use tetris_core::tetromino::{LastMove, Position, RotationState, Tetromino};
+use tetris_core::{Rotation, TetrominoKind};
+
+/// Builds a matrix from rows drawn top to bottom, where `#` is a filled cell and the last row
+/// is the bottom of the matrix.
+fn matrix(rows: &str) -> Matrix {
+    rows.lines()
+        .map(str::trim)
+        .filter(|line| !line.is_empty())
+        .rev()
+        .enumerate()
+        .flat_map(|(row, line)| {
+            line.chars()
+                .enumerate()
+                .filter(|&(_, cell)| cell == '#')
+                .map(move |(column, _)| Position {
+                    row: row as i8,
+                    column: column as i8,
+                })
+        })
+        .fold(Matrix::new(), |matrix, position| {
+            matrix.with_cell(&position, Cell::Garbage)
+        })
+}
+
+/// A T with the bottom left corner of its bounding box at `column` and `row`.
+fn t(rotation_state: RotationState, column: i8, row: i8) -> Tetromino {
+    TetrominoKind::T.placed(rotation_state, Position { row, column })
+}
+
+/// Rotates `tetromino` with the first kick that fits, the way the game does.
+fn rotate(matrix: &Matrix, tetromino: &Tetromino, rotation: Rotation) -> Tetromino {
+    assert_eq!(matrix.validate(tetromino), TetrominoValidity::Valid);
+
+    tetromino
+        .rotate(rotation)
+        .find(|kicked| matrix.validate(kicked) == TetrominoValidity::Valid)
+        .expect("Should be safe because the fixture leaves room to rotate")
+}
+
+#[test]
+fn t_spin_double() {
+    let matrix = matrix(
+        "
+        ####......
+        ###...####
+        ####.#####
+        ",
+    );
+
+    let spun = rotate(&matrix, &t(RotationState::Right, 3, 0), Rotation::Clockwise);
+
+    assert_eq!(spun.last_move(), LastMove::Rotation { kick: 0 });
+    assert_eq!(matrix.t_spin(&spun), TSpin::Full);
+    assert_eq!(matrix.solidify(&spun).filled_lines(), 2);
+}
+
+#[test]
+fn t_spin_mini() {
+    let matrix = matrix(
+        "
+        #.........
+        ..........
+        #.#.......
+        ",
+    );
+
+    let spun = rotate(&matrix, &t(RotationState::Left, 0, 0), Rotation::Clockwise);
+
+    assert_eq!(spun.rotation_state(), RotationState::Spawn);
+    assert_eq!(matrix.t_spin(&spun), TSpin::Mini);
+}
+
+#[test]
+fn tst_kick_upgrades_a_mini() {
+    // Only one corner in front of the point is filled, which would make a mini, but the T can
+    // only get in with the last kick, two rows down.
+    let matrix = matrix(
+        "
+        ..#.......
+        ..........
+        .#.#......
+        ..........
+        .#........
+        ",
+    );
+
+    let kicked = rotate(&matrix, &t(RotationState::Spawn, 2, 2), Rotation::Clockwise);
+
+    assert_eq!(kicked.last_move(), LastMove::Rotation { kick: 4 });
+    assert_eq!(kicked.minoes(), t(RotationState::Right, 1, 0).minoes());
+    assert_eq!(matrix.t_spin(&kicked), TSpin::Full);
+
+    let unkicked = rotate(
+        &matrix,
+        &t(RotationState::Reverse, 1, 0),
+        Rotation::Counterclockwise,
+    );
+
+    assert_eq!(unkicked.minoes(), kicked.minoes());
+    assert_eq!(matrix.t_spin(&unkicked), TSpin::Mini);
+}
+
+#[test]
+fn only_rotations_spin() {
+    let matrix = matrix(
+        "
+        ####......
+        ###...####
+        ####.#####
+        ",
+    );
+
+    assert_eq!(matrix.t_spin(&t(RotationState::Reverse, 3, 0)), TSpin::None);
+}
@@ -0,0 +1,199 @@
+use std::time::Duration;
+
+use tetris_core::tetromino::Position;
+use tetris_core::{
+    Settings, Step, Tetris, TetrisEvent, TetrisMove, TetrisState, TetrominoKind, TICK,
+};
+
+/// Deals I, O, L, T, J, S, Z from the seven bag.
+const SEED: u64 = 73;
+
+/// A game that has finished its countdown, with the first tetromino about to fall.
+fn playing(settings: Settings) -> Tetris {
+    let tetris = Tetris::with_settings_and_seed(
+        Settings {
+            ready_duration: Duration::ZERO,
+            ..settings
+        },
+        SEED,
+    )
+    .tick(vec![]);
+
+    assert_eq!(tetris.state(), TetrisState::Playing);
+
+    tetris
+}
+
+/// Settings where a single tick of soft drop lands the tetromino from anywhere.
+fn instant_soft_drop() -> Settings {
+    Settings {
+        soft_drop_factor: 1200,
+        ..Settings::default()
+    }
+}
+
+fn locked(tetris: &Tetris) -> bool {
+    tetris
+        .events()
+        .iter()
+        .any(|event| matches!(event, TetrisEvent::Lock(_)))
+}
+
+fn spawned(tetris: &Tetris) -> Option<TetrominoKind> {
+    tetris.events().iter().find_map(|event| match event {
+        TetrisEvent::Spawn(kind) => Some(*kind),
+        _ => None,
+    })
+}
+
+fn minoes(tetris: &Tetris) -> [Position; 4] {
+    tetris.falling_tetromino().minoes()
+}
+
+fn columns(tetris: &Tetris) -> Vec<i8> {
+    minoes(tetris)
+        .iter()
+        .map(|position| position.column)
+        .collect()
+}
+
+#[test]
+fn repeated_shifts_move_one_column() {
+    let tetris = playing(Settings::default());
+    let shifted = tetris.clone().tick(vec![
+        TetrisMove::Shift(Step::Left),
+        TetrisMove::Shift(Step::Left),
+    ]);
+
+    assert_eq!(
+        columns(&shifted),
+        columns(&tetris)
+            .iter()
+            .map(|column| column - 1)
+            .collect::<Vec<_>>()
+    );
+}
+
+#[test]
+fn repeated_soft_drops_fall_like_one() {
+    let tetris = playing(Settings::default());
+
+    assert_eq!(
+        minoes(&tetris.clone().tick(vec![TetrisMove::SoftDrop; 8])),
+        minoes(&tetris.tick(vec![TetrisMove::SoftDrop]))
+    );
+}
+
+#[test]
+fn soft_drop_gravity_saturates() {
+    let tetris = playing(Settings {
+        soft_drop_factor: u32::MAX,
+        ..Settings::default()
+    })
+    .tick(vec![TetrisMove::SoftDrop]);
+
+    assert_eq!(tetris.falling_tetromino().lowest_row(), 0);
+}
+
+#[test]
+fn auto_shift_survives_a_tiny_repeat_rate() {
+    let held = vec![TetrisMove::Shift(Step::Left)];
+
+    let tetris = playing(Settings {
+        delayed_auto_shift: Duration::ZERO,
+        auto_repeat_rate: Duration::from_nanos(1),
+        ..Settings::default()
+    })
+    .tick(held.clone())
+    .update(Duration::from_millis(4200), held.clone())
+    .update(Duration::from_millis(200), held);
+
+    assert_eq!(columns(&tetris).into_iter().min(), Some(0));
+}
+
+#[test]
+fn locks_after_the_lock_delay() {
+    let settings = instant_soft_drop();
+    let lock_delay = settings.lock_delay;
+
+    let tetris = playing(settings).tick(vec![TetrisMove::SoftDrop]);
+
+    assert_eq!(tetris.falling_tetromino().lowest_row(), 0);
+    assert!(!locked(&tetris));
+
+    // Landing took a tick of the lock delay already.
+    let tetris = tetris.update(lock_delay - TICK - Duration::from_nanos(1), vec![]);
+    assert!(!locked(&tetris));
+
+    let tetris = tetris.update(Duration::from_nanos(1), vec![]);
+    assert!(locked(&tetris));
+}
+
+#[test]
+fn locks_once_moves_run_out_of_resets() {
+    let settings = instant_soft_drop();
+    let move_reset_limit = settings.move_reset_limit;
+
+    let mut tetris = playing(settings).tick(vec![TetrisMove::SoftDrop]);
+
+    for reset in 1..=move_reset_limit {
+        let step = match reset % 2 {
+            0 => Step::Right,
+            _ => Step::Left,
+        };
+
+        tetris = tetris.update(Duration::from_millis(10), vec![TetrisMove::Shift(step)]);
+
+        assert_eq!(locked(&tetris), reset == move_reset_limit);
+    }
+}
+
+#[test]
+fn reaching_a_new_lowest_row_restores_resets() {
+    use TetrisMove::{HardDrop, Shift, SoftDrop};
+
+    let mut tetris = playing(Settings {
+        move_reset_limit: 1,
+        ..instant_soft_drop()
+    });
+
+    // Lays the I flat against the left wall, covering the first four columns of the floor.
+    for _ in 0..3 {
+        tetris = tetris.tick(vec![Shift(Step::Left)]).tick(vec![]);
+    }
+
+    tetris = tetris.tick(vec![HardDrop]);
+    assert!(locked(&tetris));
+
+    // Lands the O half on top of the I.
+    tetris = tetris
+        .tick(vec![Shift(Step::Left)])
+        .tick(vec![])
+        .tick(vec![SoftDrop]);
+    assert_eq!(tetris.falling_tetromino().lowest_row(), 1);
+
+    // Using up the only reset moves the O off of the I and down to the floor, which gives the
+    // reset back.
+    tetris = tetris.tick(vec![Shift(Step::Right), SoftDrop]);
+    assert_eq!(tetris.falling_tetromino().lowest_row(), 0);
+    assert!(!locked(&tetris));
+
+    tetris = tetris.tick(vec![]).tick(vec![Shift(Step::Right)]);
+    assert!(locked(&tetris));
+}
+
+#[test]
+fn holds_and_locks_spawn_a_tetromino() {
+    let tetris = playing(Settings::default());
+    assert_eq!(spawned(&tetris), None);
+
+    let tetris = tetris.tick(vec![TetrisMove::Hold]);
+    assert_eq!(spawned(&tetris), Some(TetrominoKind::O));
+
+    let tetris = tetris.tick(vec![]);
+    assert_eq!(spawned(&tetris), None);
+
+    let tetris = tetris.tick(vec![TetrisMove::HardDrop]);
+    assert!(locked(&tetris));
+    assert_eq!(spawned(&tetris), Some(TetrominoKind::L));
+}
@@ -0,0 +1,42 @@
+fn main() {
+    #[cfg(feature = "raylib")]
+    raylib();
+}
+
+/// Links raylib and generates its bindings from [wrapper.h].
+#[cfg(feature = "raylib")]
+fn raylib() {
+    use std::env;
+    use std::path::PathBuf;
+
+    println!("cargo:rerun-if-changed=wrapper.h");
+    println!("cargo:rerun-if-env-changed=RAYLIB_INCLUDE_DIR");
+    println!("cargo:rerun-if-env-changed=RAYLIB_LIB_DIR");
+
+    if let Ok(library_directory) = env::var("RAYLIB_LIB_DIR") {
+        println!("cargo:rustc-link-search=native={library_directory}");
+    }
+
+    println!("cargo:rustc-link-lib=raylib");
+
+    let mut builder = bindgen::Builder::default()
+        .header("wrapper.h")
+        .rustified_enum("KeyboardKey")
+        .parse_callbacks(Box::new(bindgen::CargoCallbacks));
+
+    if let Ok(include_directory) = env::var("RAYLIB_INCLUDE_DIR") {
+        builder = builder.clang_arg(format!("-I{include_directory}"));
+    }
+
+    let bindings = builder
+        .generate()
+        .expect("Should be able to generate bindings because [wrapper.h] only includes [raylib.h]");
+
+    let out_path = PathBuf::from(
+        env::var("OUT_DIR").expect("Should be safe because cargo always sets [OUT_DIR]"),
+    );
+
+    bindings
+        .write_to_file(out_path.join("bindings.rs"))
+        .expect("Should be able to write the bindings to [OUT_DIR]");
+}
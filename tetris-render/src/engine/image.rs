@@ -0,0 +1,292 @@
+use std::fs;
+use std::io;
+use std::path::Path;
+
+use super::shapes::Vector2;
+use super::vectors::{Bounds, Color};
+
+/// An in-memory RGBA frame that [super::vectors::Canvas::headless] rasterizes into, for
+/// rendering without a window or a GPU.
+#[derive(Debug, Clone, PartialEq)]
+pub struct Image {
+    width: u32,
+    height: u32,
+    pixels: Vec<Color>,
+    /// Where drawing is restricted to, if anywhere.
+    pub(super) clip: Option<Bounds>,
+}
+
+impl Image {
+    pub fn new(width: u32, height: u32) -> Self {
+        Image {
+            width,
+            height,
+            pixels: vec![Color::BLANK; (width as usize) * (height as usize)],
+            clip: None,
+        }
+    }
+
+    pub fn width(&self) -> u32 {
+        self.width
+    }
+
+    pub fn height(&self) -> u32 {
+        self.height
+    }
+
+    /// The pixel at `(x, y)`, or [None] when it lies outside of the image.
+    pub fn pixel(&self, x: u32, y: u32) -> Option<Color> {
+        match x < self.width && y < self.height {
+            true => Some(self.pixels[self.index(x, y)]),
+            false => None,
+        }
+    }
+
+    fn index(&self, x: u32, y: u32) -> usize {
+        (y as usize) * (self.width as usize) + (x as usize)
+    }
+
+    pub(super) fn clear(&mut self, color: Color) {
+        self.pixels.fill(color);
+    }
+
+    /// Blends `color` over every pixel whose center lies inside both the rectangle and the clip.
+    pub(super) fn fill_rectangle(&mut self, position: Vector2, size: Vector2, color: Color) {
+        let Bounds {
+            position: clip_position,
+            size: clip_size,
+        } = self.clip.unwrap_or(Bounds {
+            position: Vector2 { x: 0.0, y: 0.0 },
+            size: Vector2 {
+                x: self.width as f32,
+                y: self.height as f32,
+            },
+        });
+
+        let clamp_x = |x: f32| {
+            x.clamp(clip_position.x, clip_position.x + clip_size.x)
+                .round()
+                .clamp(0.0, self.width as f32) as u32
+        };
+        let clamp_y = |y: f32| {
+            y.clamp(clip_position.y, clip_position.y + clip_size.y)
+                .round()
+                .clamp(0.0, self.height as f32) as u32
+        };
+
+        let (left, right) = (clamp_x(position.x), clamp_x(position.x + size.x));
+        let (top, bottom) = (clamp_y(position.y), clamp_y(position.y + size.y));
+
+        for y in top..bottom {
+            for x in left..right {
+                let index = self.index(x, y);
+                self.pixels[index] = blend(self.pixels[index], color);
+            }
+        }
+    }
+
+    /// Draws `text` with a built-in 5x7 pixel font scaled so that a line is `font_size` tall.
+    /// Characters the font doesn't cover are left blank.
+    pub(super) fn draw_text(
+        &mut self,
+        text: &str,
+        position: Vector2,
+        font_size: f32,
+        color: Color,
+    ) {
+        let scale = font_size / (GLYPH_HEIGHT as f32 + 1.0);
+
+        for (index, character) in text.chars().enumerate() {
+            let left = position.x + scale * ((GLYPH_WIDTH + 1) * index) as f32;
+
+            for (row, bits) in glyph(character).iter().enumerate() {
+                for column in 0..GLYPH_WIDTH {
+                    if bits & (1 << (GLYPH_WIDTH - 1 - column)) != 0 {
+                        self.fill_rectangle(
+                            Vector2 {
+                                x: left + scale * column as f32,
+                                y: position.y + scale * row as f32,
+                            },
+                            Vector2 { x: scale, y: scale },
+                            color,
+                        );
+                    }
+                }
+            }
+        }
+    }
+
+    /// Encodes the image as an 8-bit RGBA PNG. The image data is stored uncompressed, which
+    /// keeps the encoder small at the cost of larger files.
+    pub fn encode_png(&self) -> Vec<u8> {
+        const SIGNATURE: [u8; 8] = [0x89, b'P', b'N', b'G', b'\r', b'\n', 0x1A, b'\n'];
+        const BIT_DEPTH: u8 = 8;
+        const RGBA: u8 = 6;
+        const NO_FILTER: u8 = 0;
+
+        let mut header = Vec::with_capacity(13);
+        header.extend_from_slice(&self.width.to_be_bytes());
+        header.extend_from_slice(&self.height.to_be_bytes());
+        header.extend_from_slice(&[BIT_DEPTH, RGBA, 0, 0, 0]);
+
+        let mut scanlines = Vec::with_capacity(self.pixels.len() * 4 + self.height as usize);
+
+        for line in self.pixels.chunks(self.width.max(1) as usize) {
+            scanlines.push(NO_FILTER);
+
+            for color in line {
+                scanlines.extend_from_slice(&[color.red, color.green, color.blue, color.alpha]);
+            }
+        }
+
+        let mut png = SIGNATURE.to_vec();
+        write_chunk(&mut png, b"IHDR", &header);
+        write_chunk(&mut png, b"IDAT", &zlib_stored(&scanlines));
+        write_chunk(&mut png, b"IEND", &[]);
+
+        png
+    }
+
+    pub fn write_png(&self, path: impl AsRef<Path>) -> io::Result<()> {
+        fs::write(path, self.encode_png())
+    }
+}
+
+/// Source-over alpha compositing of `source` onto `destination`.
+fn blend(destination: Color, source: Color) -> Color {
+    let source_alpha = u32::from(source.alpha);
+    let destination_alpha = u32::from(destination.alpha) * (255 - source_alpha) / 255;
+    let alpha = source_alpha + destination_alpha;
+
+    if alpha == 0 {
+        return Color::BLANK;
+    }
+
+    let channel = |source: u8, destination: u8| {
+        ((u32::from(source) * source_alpha + u32::from(destination) * destination_alpha) / alpha)
+            as u8
+    };
+
+    Color {
+        red: channel(source.red, destination.red),
+        green: channel(source.green, destination.green),
+        blue: channel(source.blue, destination.blue),
+        alpha: alpha as u8,
+    }
+}
+
+fn write_chunk(png: &mut Vec<u8>, kind: &[u8; 4], data: &[u8]) {
+    png.extend_from_slice(&(data.len() as u32).to_be_bytes());
+
+    let start = png.len();
+    png.extend_from_slice(kind);
+    png.extend_from_slice(data);
+
+    let crc = crc32(&png[start..]);
+    png.extend_from_slice(&crc.to_be_bytes());
+}
+
+/// A zlib stream made of uncompressed deflate blocks.
+fn zlib_stored(data: &[u8]) -> Vec<u8> {
+    const DEFLATE_32K_WINDOW: [u8; 2] = [0x78, 0x01];
+    const MAX_BLOCK_LENGTH: usize = u16::MAX as usize;
+
+    let mut stream = DEFLATE_32K_WINDOW.to_vec();
+
+    let mut blocks = data.chunks(MAX_BLOCK_LENGTH).peekable();
+
+    if blocks.peek().is_none() {
+        stream.extend_from_slice(&[1, 0, 0, 0xFF, 0xFF]);
+    }
+
+    while let Some(block) = blocks.next() {
+        let last_block = blocks.peek().is_none();
+        let length = block.len() as u16;
+
+        stream.push(u8::from(last_block));
+        stream.extend_from_slice(&length.to_le_bytes());
+        stream.extend_from_slice(&(!length).to_le_bytes());
+        stream.extend_from_slice(block);
+    }
+
+    stream.extend_from_slice(&adler32(data).to_be_bytes());
+
+    stream
+}
+
+fn crc32(data: &[u8]) -> u32 {
+    const POLYNOMIAL: u32 = 0xEDB88320;
+
+    !data.iter().fold(!0, |crc, &byte| {
+        (0..8).fold(crc ^ u32::from(byte), |crc, _| match crc & 1 {
+            1 => (crc >> 1) ^ POLYNOMIAL,
+            _ => crc >> 1,
+        })
+    })
+}
+
+fn adler32(data: &[u8]) -> u32 {
+    const MODULUS: u32 = 65521;
+
+    let (a, b) = data.iter().fold((1, 0), |(a, b), &byte| {
+        let a = (a + u32::from(byte)) % MODULUS;
+        (a, (b + a) % MODULUS)
+    });
+
+    (b << 16) | a
+}
+
+const GLYPH_WIDTH: usize = 5;
+const GLYPH_HEIGHT: usize = 7;
+
+/// The rows of `character` from top to bottom, with the leftmost pixel in the highest of the
+/// [GLYPH_WIDTH] bits.
+fn glyph(character: char) -> [u8; GLYPH_HEIGHT] {
+    match character.to_ascii_uppercase() {
+        '0' => [0x0E, 0x11, 0x13, 0x15, 0x19, 0x11, 0x0E],
+        '1' => [0x04, 0x0C, 0x04, 0x04, 0x04, 0x04, 0x0E],
+        '2' => [0x0E, 0x11, 0x01, 0x02, 0x04, 0x08, 0x1F],
+        '3' => [0x1F, 0x02, 0x04, 0x02, 0x01, 0x11, 0x0E],
+        '4' => [0x02, 0x06, 0x0A, 0x12, 0x1F, 0x02, 0x02],
+        '5' => [0x1F, 0x10, 0x1E, 0x01, 0x01, 0x11, 0x0E],
+        '6' => [0x06, 0x08, 0x10, 0x1E, 0x11, 0x11, 0x0E],
+        '7' => [0x1F, 0x01, 0x02, 0x04, 0x08, 0x08, 0x08],
+        '8' => [0x0E, 0x11, 0x11, 0x0E, 0x11, 0x11, 0x0E],
+        '9' => [0x0E, 0x11, 0x11, 0x0F, 0x01, 0x02, 0x0C],
+        'A' => [0x0E, 0x11, 0x11, 0x1F, 0x11, 0x11, 0x11],
+        'B' => [0x1E, 0x11, 0x11, 0x1E, 0x11, 0x11, 0x1E],
+        'C' => [0x0E, 0x11, 0x10, 0x10, 0x10, 0x11, 0x0E],
+        'D' => [0x1C, 0x12, 0x11, 0x11, 0x11, 0x12, 0x1C],
+        'E' => [0x1F, 0x10, 0x10, 0x1E, 0x10, 0x10, 0x1F],
+        'F' => [0x1F, 0x10, 0x10, 0x1E, 0x10, 0x10, 0x10],
+        'G' => [0x0E, 0x11, 0x10, 0x17, 0x11, 0x11, 0x0F],
+        'H' => [0x11, 0x11, 0x11, 0x1F, 0x11, 0x11, 0x11],
+        'I' => [0x0E, 0x04, 0x04, 0x04, 0x04, 0x04, 0x0E],
+        'J' => [0x07, 0x02, 0x02, 0x02, 0x02, 0x12, 0x0C],
+        'K' => [0x11, 0x12, 0x14, 0x18, 0x14, 0x12, 0x11],
+        'L' => [0x10, 0x10, 0x10, 0x10, 0x10, 0x10, 0x1F],
+        'M' => [0x11, 0x1B, 0x15, 0x15, 0x11, 0x11, 0x11],
+        'N' => [0x11, 0x11, 0x19, 0x15, 0x13, 0x11, 0x11],
+        'O' => [0x0E, 0x11, 0x11, 0x11, 0x11, 0x11, 0x0E],
+        'P' => [0x1E, 0x11, 0x11, 0x1E, 0x10, 0x10, 0x10],
+        'Q' => [0x0E, 0x11, 0x11, 0x11, 0x15, 0x12, 0x0D],
+        'R' => [0x1E, 0x11, 0x11, 0x1E, 0x14, 0x12, 0x11],
+        'S' => [0x0F, 0x10, 0x10, 0x0E, 0x01, 0x01, 0x1E],
+        'T' => [0x1F, 0x04, 0x04, 0x04, 0x04, 0x04, 0x04],
+        'U' => [0x11, 0x11, 0x11, 0x11, 0x11, 0x11, 0x0E],
+        'V' => [0x11, 0x11, 0x11, 0x11, 0x11, 0x0A, 0x04],
+        'W' => [0x11, 0x11, 0x11, 0x15, 0x15, 0x15, 0x0A],
+        'X' => [0x11, 0x11, 0x0A, 0x04, 0x0A, 0x11, 0x11],
+        'Y' => [0x11, 0x11, 0x11, 0x0A, 0x04, 0x04, 0x04],
+        'Z' => [0x1F, 0x01, 0x02, 0x04, 0x08, 0x10, 0x1F],
+        ':' => [0x00, 0x0C, 0x0C, 0x00, 0x0C, 0x0C, 0x00],
+        '.' => [0x00, 0x00, 0x00, 0x00, 0x00, 0x0C, 0x0C],
+        ',' => [0x00, 0x00, 0x00, 0x00, 0x0C, 0x04, 0x08],
+        '!' => [0x04, 0x04, 0x04, 0x04, 0x04, 0x00, 0x04],
+        '?' => [0x0E, 0x11, 0x01, 0x02, 0x04, 0x00, 0x04],
+        '-' => [0x00, 0x00, 0x00, 0x1F, 0x00, 0x00, 0x00],
+        '\'' => [0x0C, 0x04, 0x08, 0x00, 0x00, 0x00, 0x00],
+        '/' => [0x00, 0x01, 0x02, 0x04, 0x08, 0x10, 0x00],
+        _ => [0x00; GLYPH_HEIGHT],
+    }
+}
@@ -0,0 +1,26 @@
+use super::bindings;
+
+pub use bindings::KeyboardKey;
+
+/// Reads the keyboard. Only obtainable through [super::RaylibBuilder::build], because raylib
+/// can't poll input without an open window.
+pub struct Input {
+    pub(super) _private: (),
+}
+
+impl Input {
+    /// Whether `key` is being held down.
+    pub fn key_down(&self, key: KeyboardKey) -> bool {
+        unsafe { bindings::IsKeyDown(key as i32) }
+    }
+
+    /// Whether `key` went down this frame.
+    pub fn key_pressed(&self, key: KeyboardKey) -> bool {
+        unsafe { bindings::IsKeyPressed(key as i32) }
+    }
+
+    /// Whether `key` went up this frame.
+    pub fn key_released(&self, key: KeyboardKey) -> bool {
+        unsafe { bindings::IsKeyReleased(key as i32) }
+    }
+}
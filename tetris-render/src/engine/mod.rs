@@ -0,0 +1,15 @@
+#[cfg(feature = "raylib")]
+mod bindings;
+#[cfg(feature = "raylib")]
+mod raylib;
+
+pub mod image;
+#[cfg(feature = "raylib")]
+pub mod input;
+pub mod shapes;
+pub mod vectors;
+#[cfg(feature = "raylib")]
+pub mod window;
+
+#[cfg(feature = "raylib")]
+pub use raylib::{Raylib, RaylibBuilder};
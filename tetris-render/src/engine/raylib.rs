@@ -0,0 +1,64 @@
+use std::ffi::CString;
+use std::sync::atomic::{AtomicBool, Ordering};
+
+use super::bindings;
+use super::input::Input;
+use super::window::Window;
+
+pub struct Raylib {
+    pub window: Window,
+    pub input: Input,
+}
+
+pub struct RaylibBuilder {
+    title: CString,
+    width: u16,
+    height: u16,
+    flags: u32,
+}
+
+impl RaylibBuilder {
+    pub fn new(title: &str, width: u16, height: u16) -> Self {
+        RaylibBuilder {
+            title: CString::new(title).expect("Window titles should not contain a nul byte"),
+            width,
+            height,
+            flags: 0,
+        }
+    }
+
+    pub fn vsync(mut self) -> Self {
+        self.flags |= bindings::ConfigFlags_FLAG_VSYNC_HINT;
+        self
+    }
+
+    pub fn msaa(mut self) -> Self {
+        self.flags |= bindings::ConfigFlags_FLAG_MSAA_4X_HINT;
+        self
+    }
+
+    pub fn fullscreen(mut self) -> Self {
+        self.flags |= bindings::ConfigFlags_FLAG_FULLSCREEN_MODE;
+        self
+    }
+
+    /// Opens the window. Raylib only supports a single window per process, so this panics when
+    /// called a second time.
+    pub fn build(self) -> Raylib {
+        static INITIALIZED: AtomicBool = AtomicBool::new(false);
+
+        if INITIALIZED.swap(true, Ordering::SeqCst) {
+            panic!("Raylib should only be initialized once");
+        }
+
+        unsafe {
+            bindings::SetConfigFlags(self.flags);
+            bindings::InitWindow(self.width.into(), self.height.into(), self.title.as_ptr());
+        }
+
+        Raylib {
+            window: Window { _private: () },
+            input: Input { _private: () },
+        }
+    }
+}
@@ -0,0 +1,75 @@
+use std::ops::{Add, Mul};
+
+#[cfg(feature = "raylib")]
+use super::bindings;
+
+#[derive(Debug, Clone, Copy, PartialEq)]
+pub struct Vector2 {
+    pub x: f32,
+    pub y: f32,
+}
+
+impl Add for Vector2 {
+    type Output = Self;
+
+    fn add(self, other: Self) -> Self::Output {
+        Vector2 {
+            x: self.x + other.x,
+            y: self.y + other.y,
+        }
+    }
+}
+
+#[cfg(feature = "raylib")]
+impl From<Vector2> for bindings::Vector2 {
+    fn from(Vector2 { x, y }: Vector2) -> Self {
+        bindings::Vector2 { x, y }
+    }
+}
+
+#[derive(Debug, Clone, Copy, PartialEq)]
+pub struct Rectangle {
+    pub size: Vector2,
+}
+
+impl Mul<f32> for Vector2 {
+    type Output = Self;
+
+    fn mul(self, scale: f32) -> Self::Output {
+        Vector2 {
+            x: self.x * scale,
+            y: self.y * scale,
+        }
+    }
+}
+
+/// Moves and uniformly scales whatever is drawn through a [super::vectors::Renderer], scaling
+/// around the origin before moving by `offset`.
+#[derive(Debug, Clone, Copy, PartialEq)]
+pub struct Transform {
+    pub offset: Vector2,
+    pub scale: f32,
+}
+
+impl Transform {
+    pub const IDENTITY: Transform = Transform {
+        offset: Vector2 { x: 0.0, y: 0.0 },
+        scale: 1.0,
+    };
+
+    pub const fn translate(offset: Vector2) -> Self {
+        Transform { offset, scale: 1.0 }
+    }
+
+    /// The transform that applies `inner` first and then `self`.
+    pub fn compose(self, inner: Transform) -> Self {
+        Transform {
+            offset: self.apply(inner.offset),
+            scale: self.scale * inner.scale,
+        }
+    }
+
+    pub fn apply(self, point: Vector2) -> Vector2 {
+        self.offset + point * self.scale
+    }
+}
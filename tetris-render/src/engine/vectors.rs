@@ -0,0 +1,446 @@
+#[cfg(feature = "raylib")]
+use std::ffi::CString;
+
+#[cfg(feature = "raylib")]
+use super::bindings;
+use super::image::Image;
+use super::shapes::{Rectangle, Transform, Vector2};
+
+pub trait Drawable<'a> {
+    fn draw<C: Renderer>(&self, canvas: C) -> C;
+}
+
+/// Everything [Drawable]s need to draw themselves, so that the same drawing code can target
+/// any backend. Positions and sizes go through the current transform, and nothing is drawn
+/// outside of the current clip.
+pub trait Renderer: Sized {
+    fn clear(self, color: Color) -> Self;
+
+    fn fill_rectangle(self, position: Vector2, size: Vector2, color: Color) -> Self;
+
+    /// Outlines the rectangle with a border `thickness` thick, drawn on the inside of its edges.
+    fn stroke_rectangle(
+        self,
+        position: Vector2,
+        size: Vector2,
+        thickness: f32,
+        color: Color,
+    ) -> Self;
+
+    fn text(self, text: &str, position: Vector2, font_size: f32, color: Color) -> Self;
+
+    /// Restricts drawing to the intersection of the rectangle and the current clip, until the
+    /// matching [Renderer::pop_clip].
+    fn push_clip(self, position: Vector2, size: Vector2) -> Self;
+
+    fn pop_clip(self) -> Self;
+
+    /// Applies `transform` on top of the current transform, until the matching
+    /// [Renderer::pop_transform].
+    fn push_transform(self, transform: Transform) -> Self;
+
+    fn pop_transform(self) -> Self;
+
+    /// How many frames the backend presents each second, if it presents frames at all.
+    fn frames_per_second(&self) -> Option<u32>;
+
+    fn draw<'a>(self, drawable: &impl Drawable<'a>) -> Self {
+        drawable.draw(self)
+    }
+}
+
+/// A frame being drawn, either to the window obtained from [super::window::Window::canvas], or
+/// in memory by [Canvas::headless]. A window frame is presented when the canvas is dropped.
+pub struct Canvas {
+    target: Target,
+    transforms: Vec<Transform>,
+    clips: Vec<Bounds>,
+}
+
+enum Target {
+    #[cfg(feature = "raylib")]
+    Window,
+    Image(Image),
+}
+
+/// An axis aligned rectangle in window coordinates.
+#[derive(Debug, Clone, Copy, PartialEq)]
+pub(super) struct Bounds {
+    pub(super) position: Vector2,
+    pub(super) size: Vector2,
+}
+
+impl Bounds {
+    fn intersection(self, other: Bounds) -> Bounds {
+        let left = self.position.x.max(other.position.x);
+        let top = self.position.y.max(other.position.y);
+        let right = (self.position.x + self.size.x).min(other.position.x + other.size.x);
+        let bottom = (self.position.y + self.size.y).min(other.position.y + other.size.y);
+
+        Bounds {
+            position: Vector2 { x: left, y: top },
+            size: Vector2 {
+                x: (right - left).max(0.0),
+                y: (bottom - top).max(0.0),
+            },
+        }
+    }
+}
+
+impl Canvas {
+    #[cfg(feature = "raylib")]
+    pub(super) fn window() -> Self {
+        Canvas::new(Target::Window)
+    }
+
+    /// A canvas that rasterizes into a transparent `width` by `height` [Image] in software, which
+    /// works without a window or a GPU.
+    pub fn headless(width: u32, height: u32) -> Self {
+        Canvas::new(Target::Image(Image::new(width, height)))
+    }
+
+    fn new(target: Target) -> Self {
+        Canvas {
+            target,
+            transforms: Vec::new(),
+            clips: Vec::new(),
+        }
+    }
+
+    /// The drawn frame of a [Canvas::headless], or [None] when drawing to the window.
+    pub fn into_image(mut self) -> Option<Image> {
+        match &mut self.target {
+            Target::Image(image) => Some(std::mem::replace(image, Image::new(0, 0))),
+            #[cfg(feature = "raylib")]
+            Target::Window => None,
+        }
+    }
+
+    fn transform(&self) -> Transform {
+        self.transforms
+            .last()
+            .copied()
+            .unwrap_or(Transform::IDENTITY)
+    }
+
+    fn bounds(&self, position: Vector2, size: Vector2) -> Bounds {
+        let transform = self.transform();
+
+        Bounds {
+            position: transform.apply(position),
+            size: size * transform.scale,
+        }
+    }
+
+    /// Makes the backend clip to the innermost clip left on the stack.
+    fn apply_clip(&mut self) {
+        let clip = self.clips.last().copied();
+
+        match (&mut self.target, clip) {
+            #[cfg(feature = "raylib")]
+            (Target::Window, Some(Bounds { position, size })) => unsafe {
+                bindings::BeginScissorMode(
+                    position.x as i32,
+                    position.y as i32,
+                    size.x as i32,
+                    size.y as i32,
+                )
+            },
+            #[cfg(feature = "raylib")]
+            (Target::Window, None) => unsafe { bindings::EndScissorMode() },
+            (Target::Image(image), clip) => image.clip = clip,
+        }
+    }
+}
+
+impl Renderer for Canvas {
+    fn clear(mut self, color: Color) -> Self {
+        match &mut self.target {
+            #[cfg(feature = "raylib")]
+            Target::Window => unsafe { bindings::ClearBackground(color.into()) },
+            Target::Image(image) => image.clear(color),
+        }
+
+        self
+    }
+
+    fn fill_rectangle(mut self, position: Vector2, size: Vector2, color: Color) -> Self {
+        let Bounds { position, size } = self.bounds(position, size);
+
+        match &mut self.target {
+            #[cfg(feature = "raylib")]
+            Target::Window => unsafe {
+                bindings::DrawRectangleV(position.into(), size.into(), color.into())
+            },
+            Target::Image(image) => image.fill_rectangle(position, size, color),
+        }
+
+        self
+    }
+
+    fn stroke_rectangle(
+        mut self,
+        position: Vector2,
+        size: Vector2,
+        thickness: f32,
+        color: Color,
+    ) -> Self {
+        let Bounds { position, size } = self.bounds(position, size);
+        let thickness = thickness * self.transform().scale;
+
+        match &mut self.target {
+            #[cfg(feature = "raylib")]
+            Target::Window => unsafe {
+                bindings::DrawRectangleLinesEx(
+                    bindings::Rectangle {
+                        x: position.x,
+                        y: position.y,
+                        width: size.x,
+                        height: size.y,
+                    },
+                    thickness,
+                    color.into(),
+                )
+            },
+            Target::Image(image) => {
+                let horizontal = Vector2 {
+                    x: size.x,
+                    y: thickness,
+                };
+                let vertical = Vector2 {
+                    x: thickness,
+                    y: size.y - 2.0 * thickness,
+                };
+
+                image.fill_rectangle(position, horizontal, color);
+                image.fill_rectangle(
+                    position
+                        + Vector2 {
+                            x: 0.0,
+                            y: size.y - thickness,
+                        },
+                    horizontal,
+                    color,
+                );
+                image.fill_rectangle(
+                    position
+                        + Vector2 {
+                            x: 0.0,
+                            y: thickness,
+                        },
+                    vertical,
+                    color,
+                );
+                image.fill_rectangle(
+                    position
+                        + Vector2 {
+                            x: size.x - thickness,
+                            y: thickness,
+                        },
+                    vertical,
+                    color,
+                );
+            }
+        }
+
+        self
+    }
+
+    fn text(mut self, text: &str, position: Vector2, font_size: f32, color: Color) -> Self {
+        let transform = self.transform();
+        let position = transform.apply(position);
+        let font_size = font_size * transform.scale;
+
+        match &mut self.target {
+            #[cfg(feature = "raylib")]
+            Target::Window => {
+                let text = CString::new(text).expect("Drawn text should not contain a nul byte");
+
+                unsafe {
+                    bindings::DrawText(
+                        text.as_ptr(),
+                        position.x as i32,
+                        position.y as i32,
+                        font_size as i32,
+                        color.into(),
+                    )
+                }
+            }
+            Target::Image(image) => image.draw_text(text, position, font_size, color),
+        }
+
+        self
+    }
+
+    fn push_clip(mut self, position: Vector2, size: Vector2) -> Self {
+        let bounds = self.bounds(position, size);
+
+        let clip = match self.clips.last() {
+            Some(&clip) => clip.intersection(bounds),
+            None => bounds,
+        };
+
+        self.clips.push(clip);
+        self.apply_clip();
+
+        self
+    }
+
+    fn pop_clip(mut self) -> Self {
+        self.clips.pop();
+        self.apply_clip();
+
+        self
+    }
+
+    fn push_transform(mut self, transform: Transform) -> Self {
+        self.transforms.push(self.transform().compose(transform));
+        self
+    }
+
+    fn pop_transform(mut self) -> Self {
+        self.transforms.pop();
+        self
+    }
+
+    /// Headless canvases have no frame rate, since they never present their frames.
+    fn frames_per_second(&self) -> Option<u32> {
+        match self.target {
+            #[cfg(feature = "raylib")]
+            Target::Window => u32::try_from(unsafe { bindings::GetFPS() }).ok(),
+            Target::Image(_) => None,
+        }
+    }
+}
+
+#[cfg(feature = "raylib")]
+impl Drop for Canvas {
+    fn drop(&mut self) {
+        if let Target::Window = self.target {
+            unsafe { bindings::EndDrawing() }
+        }
+    }
+}
+
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub struct Color {
+    pub red: u8,
+    pub green: u8,
+    pub blue: u8,
+    pub alpha: u8,
+}
+
+impl Color {
+    pub const fn new(red: u8, green: u8, blue: u8) -> Self {
+        Color {
+            red,
+            green,
+            blue,
+            alpha: u8::MAX,
+        }
+    }
+
+    /// The same color at `alpha` opacity, from `0.0` for invisible to `1.0` for opaque.
+    pub fn fade(self, alpha: f32) -> Self {
+        Color {
+            alpha: (alpha.clamp(0.0, 1.0) * f32::from(u8::MAX)) as u8,
+            ..self
+        }
+    }
+
+    pub const LIGHT_GRAY: Color = Color::new(200, 200, 200);
+    pub const GRAY: Color = Color::new(130, 130, 130);
+    pub const DARK_GRAY: Color = Color::new(80, 80, 80);
+    pub const YELLOW: Color = Color::new(253, 249, 0);
+    pub const GOLD: Color = Color::new(255, 203, 0);
+    pub const ORANGE: Color = Color::new(255, 161, 0);
+    pub const PINK: Color = Color::new(255, 109, 194);
+    pub const RED: Color = Color::new(230, 41, 55);
+    pub const MAROON: Color = Color::new(190, 33, 55);
+    pub const GREEN: Color = Color::new(0, 228, 48);
+    pub const LIME: Color = Color::new(0, 158, 47);
+    pub const DARK_GREEN: Color = Color::new(0, 117, 44);
+    pub const SKY_BLUE: Color = Color::new(102, 191, 255);
+    pub const BLUE: Color = Color::new(0, 121, 241);
+    pub const DARK_BLUE: Color = Color::new(0, 82, 172);
+    pub const PURPLE: Color = Color::new(200, 122, 255);
+    pub const VIOLET: Color = Color::new(135, 60, 190);
+    pub const DARK_PURPLE: Color = Color::new(112, 31, 126);
+    pub const BEIGE: Color = Color::new(211, 176, 131);
+    pub const BROWN: Color = Color::new(127, 106, 79);
+    pub const DARK_BROWN: Color = Color::new(76, 63, 47);
+    pub const WHITE: Color = Color::new(255, 255, 255);
+    pub const BLACK: Color = Color::new(0, 0, 0);
+    pub const BLANK: Color = Color::new(0, 0, 0).with_alpha(0);
+    pub const MAGENTA: Color = Color::new(255, 0, 255);
+    pub const RAY_WHITE: Color = Color::new(245, 245, 245);
+
+    const fn with_alpha(self, alpha: u8) -> Self {
+        Color { alpha, ..self }
+    }
+}
+
+#[cfg(feature = "raylib")]
+impl From<Color> for bindings::Color {
+    fn from(color: Color) -> Self {
+        bindings::Color {
+            r: color.red,
+            g: color.green,
+            b: color.blue,
+            a: color.alpha,
+        }
+    }
+}
+
+pub struct RectangleGraphic {
+    pub rectangle: Rectangle,
+    pub position: Vector2,
+    pub color: Color,
+}
+
+impl<'a> Drawable<'a> for RectangleGraphic {
+    fn draw<C: Renderer>(&self, canvas: C) -> C {
+        canvas.fill_rectangle(self.position, self.rectangle.size, self.color)
+    }
+}
+
+pub struct TextGraphic<'a> {
+    pub text: &'a str,
+    pub position: Vector2,
+    pub font_size: f32,
+    pub color: Color,
+}
+
+impl<'a, 'b> Drawable<'a> for TextGraphic<'b> {
+    fn draw<C: Renderer>(&self, canvas: C) -> C {
+        canvas.text(self.text, self.position, self.font_size, self.color)
+    }
+}
+
+/// Fills the whole frame.
+pub struct Background {
+    pub color: Color,
+}
+
+impl<'a> Drawable<'a> for Background {
+    fn draw<C: Renderer>(&self, canvas: C) -> C {
+        canvas.clear(self.color)
+    }
+}
+
+/// The frame rate reported by the renderer. Draws nothing for renderers without one.
+pub struct FPSGraphic {
+    pub position: Vector2,
+}
+
+impl<'a> Drawable<'a> for FPSGraphic {
+    fn draw<C: Renderer>(&self, canvas: C) -> C {
+        match canvas.frames_per_second() {
+            Some(frames_per_second) => {
+                let text = format!("{frames_per_second} FPS");
+                canvas.text(&text, self.position, 20.0, Color::LIME)
+            }
+            None => canvas,
+        }
+    }
+}
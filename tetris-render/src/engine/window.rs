@@ -0,0 +1,33 @@
+use std::time::Duration;
+
+use super::bindings;
+use super::vectors::Canvas;
+
+/// The one window raylib manages. It closes when dropped.
+pub struct Window {
+    pub(super) _private: (),
+}
+
+impl Window {
+    pub fn should_close(&self) -> bool {
+        unsafe { bindings::WindowShouldClose() }
+    }
+
+    /// How long the last frame took to draw.
+    pub fn frame_time(&self) -> Duration {
+        Duration::from_secs_f32(unsafe { bindings::GetFrameTime() })
+    }
+
+    /// Starts drawing a frame, which is presented once the returned [Canvas] is dropped.
+    pub fn canvas(&self) -> Canvas {
+        unsafe { bindings::BeginDrawing() };
+
+        Canvas::window()
+    }
+}
+
+impl Drop for Window {
+    fn drop(&mut self) {
+        unsafe { bindings::CloseWindow() }
+    }
+}
@@ -0,0 +1,37 @@
+use tetris_core::hold_queue::HoldQueue;
+
+use super::engine::{
+    shapes::{Rectangle, Vector2},
+    vectors::{Color, Drawable, RectangleGraphic, Renderer},
+};
+use super::tetromino::{TetrominoColor, TetrominoGraphic};
+
+impl<'a> Drawable<'a> for HoldQueue {
+    fn draw<C: Renderer>(&self, canvas: C) -> C {
+        const POSITION: Vector2 = Vector2 { x: 141.0, y: 21.0 };
+        const SIZE: Vector2 = Vector2 { x: 232.0, y: 180.0 };
+
+        let canvas = canvas.draw(&RectangleGraphic {
+            rectangle: Rectangle { size: SIZE },
+            position: POSITION,
+            color: Color::GRAY,
+        });
+
+        match self.held() {
+            Some(held) => canvas.draw(&TetrominoGraphic {
+                tetromino: &held.new(),
+                position: POSITION
+                    + Vector2 {
+                        x: SIZE.x / 2.0,
+                        y: SIZE.y / 2.0,
+                    },
+                cell_size: 45.0,
+                color: match self.can_hold() {
+                    true => held.color(),
+                    false => Color::DARK_GRAY,
+                },
+            }),
+            None => canvas,
+        }
+    }
+}
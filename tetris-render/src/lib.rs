@@ -0,0 +1,20 @@
+//! Draws a [tetris_core::Tetris] through the [engine], either to a raylib window or headlessly
+//! to an [engine::image::Image].
+//!
+//! The window and keyboard need the default `raylib` feature. Without it only the headless
+//! canvas is built, which needs neither raylib nor libclang.
+
+pub mod engine;
+pub mod hold_queue;
+pub mod matrix;
+pub mod moves;
+pub mod next_queue;
+pub mod scoring;
+#[allow(clippy::module_inception)]
+pub mod tetris;
+pub mod tetromino;
+
+pub use matrix::{in_playfield, CellGraphic};
+pub use moves::MovesGraphic;
+pub use tetris::InterpolatedTetris;
+pub use tetromino::{TetrominoColor, TetrominoGraphic};
@@ -0,0 +1,98 @@
+use tetris_core::matrix::{Cell, Matrix, PLAYFIELD_COLUMNS, PLAYFIELD_ROWS};
+use tetris_core::tetromino::Position;
+
+use super::engine::{
+    shapes::{Rectangle, Transform, Vector2},
+    vectors::{Color, Drawable, RectangleGraphic, Renderer},
+};
+use super::tetromino::TetrominoColor;
+pub const CELL_SIZE: f32 = 45.0;
+
+/// The top left corner of the visible playfield.
+pub const PLAYFIELD_POSITION: Vector2 = Vector2 { x: 403.0, y: 111.0 };
+
+/// How many vanish zone rows peek out above the playfield, so that tetrominoes spawning in the
+/// two rows right above it can be seen.
+const VANISH_ZONE_ROWS: usize = 2;
+
+/// Draws with `draw` in playfield coordinates, where the top left corner of the visible
+/// playfield is the origin, clipped to the visible playfield and the peek into the vanish zone.
+pub fn in_playfield<C: Renderer>(canvas: C, draw: impl FnOnce(C) -> C) -> C {
+    let canvas = canvas
+        .push_transform(Transform::translate(PLAYFIELD_POSITION))
+        .push_clip(
+            Vector2 {
+                x: 0.0,
+                y: -CELL_SIZE * (VANISH_ZONE_ROWS as f32),
+            },
+            Vector2 {
+                x: CELL_SIZE * (PLAYFIELD_COLUMNS as f32),
+                y: CELL_SIZE * ((PLAYFIELD_ROWS + VANISH_ZONE_ROWS) as f32),
+            },
+        );
+
+    draw(canvas).pop_clip().pop_transform()
+}
+
+impl<'a> Drawable<'a> for Matrix {
+    fn draw<C: Renderer>(&self, canvas: C) -> C {
+        let canvas = canvas.draw(&RectangleGraphic {
+            rectangle: Rectangle {
+                size: Vector2 {
+                    x: CELL_SIZE * (PLAYFIELD_COLUMNS as f32),
+                    y: CELL_SIZE * (PLAYFIELD_ROWS as f32),
+                },
+            },
+            position: PLAYFIELD_POSITION,
+            color: Color::GRAY,
+        });
+
+        in_playfield(canvas, |mut canvas| {
+            for row in 0..PLAYFIELD_ROWS + VANISH_ZONE_ROWS {
+                for column in 0..PLAYFIELD_COLUMNS {
+                    let position = Position {
+                        row: row as i8,
+                        column: column as i8,
+                    };
+
+                    let color = match self.cell(&position) {
+                        Some(Cell::Filled(kind)) => kind.color(),
+                        Some(Cell::Garbage) => Color::DARK_GRAY,
+                        Some(Cell::Empty) | None => continue,
+                    };
+
+                    canvas = canvas.draw(&CellGraphic { position, color });
+                }
+            }
+
+            canvas
+        })
+    }
+}
+
+/// A single cell drawn at its place in the matrix, whether or not it has been solidified. Only
+/// meant to be drawn [in_playfield].
+pub struct CellGraphic {
+    pub position: Position,
+    pub color: Color,
+}
+
+impl<'a> Drawable<'a> for CellGraphic {
+    fn draw<C: Renderer>(&self, canvas: C) -> C {
+        let rows_from_top = (PLAYFIELD_ROWS as f32) - f32::from(self.position.row) - 1.0;
+
+        canvas.draw(&RectangleGraphic {
+            rectangle: Rectangle {
+                size: Vector2 {
+                    x: CELL_SIZE,
+                    y: CELL_SIZE,
+                },
+            },
+            position: Vector2 {
+                x: CELL_SIZE * f32::from(self.position.column),
+                y: CELL_SIZE * rows_from_top,
+            },
+            color: self.color,
+        })
+    }
+}
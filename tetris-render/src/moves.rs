@@ -0,0 +1,57 @@
+use tetris_core::tetromino::{Rotation, Step};
+use tetris_core::TetrisMove;
+
+use super::engine::{
+    shapes::{Rectangle, Vector2},
+    vectors::{Color, Drawable, RectangleGraphic, Renderer, TextGraphic},
+};
+
+/// Every move as a row of keys, with the ones made during a frame lit up.
+pub struct MovesGraphic<'a> {
+    pub moves: &'a [TetrisMove],
+}
+
+impl<'a, 'b> Drawable<'a> for MovesGraphic<'b> {
+    fn draw<C: Renderer>(&self, canvas: C) -> C {
+        const POSITION: Vector2 = Vector2 { x: 141.0, y: 540.0 };
+        const KEY_SIZE: Vector2 = Vector2 { x: 232.0, y: 40.0 };
+        const KEY_SPACING: f32 = 8.0;
+
+        [
+            (TetrisMove::Shift(Step::Left), "LEFT"),
+            (TetrisMove::Shift(Step::Right), "RIGHT"),
+            (TetrisMove::Rotate(Rotation::Clockwise), "ROTATE CW"),
+            (TetrisMove::Rotate(Rotation::Counterclockwise), "ROTATE CCW"),
+            (TetrisMove::SoftDrop, "SOFT DROP"),
+            (TetrisMove::HardDrop, "HARD DROP"),
+            (TetrisMove::Hold, "HOLD"),
+        ]
+        .iter()
+        .enumerate()
+        .fold(canvas, |canvas, (index, (tetris_move, label))| {
+            let position = POSITION
+                + Vector2 {
+                    x: 0.0,
+                    y: (KEY_SIZE.y + KEY_SPACING) * (index as f32),
+                };
+
+            let (background, foreground) = match self.moves.contains(tetris_move) {
+                true => (Color::DARK_GRAY, Color::RAY_WHITE),
+                false => (Color::LIGHT_GRAY, Color::GRAY),
+            };
+
+            canvas
+                .draw(&RectangleGraphic {
+                    rectangle: Rectangle { size: KEY_SIZE },
+                    position,
+                    color: background,
+                })
+                .draw(&TextGraphic {
+                    text: label,
+                    position: position + Vector2 { x: 10.0, y: 8.0 },
+                    font_size: 25.0,
+                    color: foreground,
+                })
+        })
+    }
+}
@@ -0,0 +1,53 @@
+use tetris_core::next_queue::NextQueue;
+use tetris_core::tetromino::TetrominoKind;
+
+use super::engine::{
+    shapes::{Rectangle, Transform, Vector2},
+    vectors::{Color, Drawable, RectangleGraphic, Renderer},
+};
+use super::tetromino::{TetrominoColor, TetrominoGraphic};
+
+impl<'a, R: Iterator<Item = TetrominoKind>, const SIZE: usize> Drawable<'a> for NextQueue<R, SIZE> {
+    fn draw<C: Renderer>(&self, canvas: C) -> C {
+        const POSITION: Vector2 = Vector2 { x: 883.0, y: 21.0 };
+        const DIMENSIONS: Vector2 = Vector2 { x: 232.0, y: 712.0 };
+
+        /// How many times taller the slot of the very next tetromino is than the others.
+        const FIRST_SLOT_SCALE: f32 = 2.0;
+
+        let canvas =
+            canvas
+                .push_transform(Transform::translate(POSITION))
+                .draw(&RectangleGraphic {
+                    rectangle: Rectangle { size: DIMENSIONS },
+                    position: Vector2 { x: 0.0, y: 0.0 },
+                    color: Color::GRAY,
+                });
+
+        let slot_height = DIMENSIONS.y / ((SIZE as f32) - 1.0 + FIRST_SLOT_SCALE);
+
+        let (canvas, _) =
+            self.upcoming()
+                .enumerate()
+                .fold((canvas, 0.0), |(canvas, y), (index, kind)| {
+                    let height = match index {
+                        0 => slot_height * FIRST_SLOT_SCALE,
+                        _ => slot_height,
+                    };
+
+                    let canvas = canvas.draw(&TetrominoGraphic {
+                        tetromino: &kind.new(),
+                        position: Vector2 {
+                            x: DIMENSIONS.x / 2.0,
+                            y: y + height / 2.0,
+                        },
+                        cell_size: (height / 4.0).min(DIMENSIONS.x / 5.0),
+                        color: kind.color(),
+                    });
+
+                    (canvas, y + height)
+                });
+
+        canvas.pop_transform()
+    }
+}
@@ -0,0 +1,46 @@
+use tetris_core::scoring::Score;
+
+use super::engine::{
+    shapes::Vector2,
+    vectors::{Color, Drawable, Renderer, TextGraphic},
+};
+
+impl<'a> Drawable<'a> for Score {
+    fn draw<C: Renderer>(&self, canvas: C) -> C {
+        const POSITION: Vector2 = Vector2 { x: 141.0, y: 241.0 };
+        const LINE_HEIGHT: f32 = 45.0;
+
+        [
+            ("SCORE", self.points().to_string()),
+            ("LEVEL", self.level().to_string()),
+            ("LINES", self.lines().to_string()),
+        ]
+        .iter()
+        .enumerate()
+        .fold(canvas, |canvas, (index, (label, value))| {
+            let position = POSITION
+                + Vector2 {
+                    x: 0.0,
+                    y: LINE_HEIGHT * 2.0 * (index as f32),
+                };
+
+            canvas
+                .draw(&TextGraphic {
+                    text: label,
+                    position,
+                    font_size: 30.0,
+                    color: Color::GRAY,
+                })
+                .draw(&TextGraphic {
+                    text: value,
+                    position: position
+                        + Vector2 {
+                            x: 0.0,
+                            y: LINE_HEIGHT,
+                        },
+                    font_size: 40.0,
+                    color: Color::BLACK,
+                })
+        })
+    }
+}
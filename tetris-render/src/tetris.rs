@@ -0,0 +1,122 @@
+use tetris_core::tetris::{Tetris, TetrisState};
+use tetris_core::tetromino::Tetromino;
+
+use super::engine::{
+    shapes::{Rectangle, Transform, Vector2},
+    vectors::{Color, Drawable, RectangleGraphic, Renderer, TextGraphic},
+};
+use super::matrix::{in_playfield, CellGraphic, CELL_SIZE, PLAYFIELD_POSITION};
+use super::tetromino::TetrominoColor;
+
+impl<'a> Drawable<'a> for Tetris {
+    fn draw<C: Renderer>(&self, canvas: C) -> C {
+        canvas.draw(&InterpolatedTetris {
+            tetris: self,
+            previous: None,
+            alpha: 1.0,
+        })
+    }
+}
+
+/// Draws the falling tetromino part of the way between where it was before the last tick and
+/// where it is now, so that it glides instead of jumping whenever a tick happens to land
+/// between two rendered frames.
+pub struct InterpolatedTetris<'a> {
+    pub tetris: &'a Tetris,
+    /// The falling tetromino before the last tick, or [None] when it should not be
+    /// interpolated because a tetromino spawned, was held or locked during the tick.
+    pub previous: Option<&'a Tetromino>,
+    /// How far to go from `previous` to the current falling tetromino, from 0 to 1.
+    pub alpha: f32,
+}
+
+impl<'a, 'b> Drawable<'a> for InterpolatedTetris<'b> {
+    fn draw<C: Renderer>(&self, canvas: C) -> C {
+        let tetris = self.tetris;
+        let falling_tetromino = tetris.falling_tetromino();
+
+        let offset = match self.previous {
+            Some(previous)
+                if previous.kind() == falling_tetromino.kind()
+                    && previous.rotation_state() == falling_tetromino.rotation_state() =>
+            {
+                let from = previous.minoes()[0];
+                let to = falling_tetromino.minoes()[0];
+                let remaining = 1.0 - self.alpha.clamp(0.0, 1.0);
+
+                Vector2 {
+                    x: CELL_SIZE * f32::from(from.column - to.column) * remaining,
+                    y: CELL_SIZE * f32::from(to.row - from.row) * remaining,
+                }
+            }
+            _ => Vector2 { x: 0.0, y: 0.0 },
+        };
+
+        let canvas = in_playfield(canvas.draw(tetris.matrix()), |mut canvas| {
+            if let TetrisState::Playing | TetrisState::Paused = tetris.state() {
+                if let Some(ghost) = tetris.ghost_tetromino() {
+                    for position in ghost.minoes() {
+                        canvas = canvas.draw(&CellGraphic {
+                            position,
+                            color: ghost.kind().color().fade(0.3),
+                        });
+                    }
+                }
+
+                canvas = canvas.push_transform(Transform::translate(offset));
+
+                for position in falling_tetromino.minoes() {
+                    canvas = canvas.draw(&CellGraphic {
+                        position,
+                        color: falling_tetromino.kind().color(),
+                    });
+                }
+
+                canvas = canvas.pop_transform();
+            }
+
+            canvas
+        });
+
+        canvas
+            .draw(tetris.next_queue())
+            .draw(tetris.hold_queue())
+            .draw(tetris.score())
+    }
+}
+
+impl<'a> Drawable<'a> for TetrisState {
+    fn draw<C: Renderer>(&self, canvas: C) -> C {
+        const PLAYFIELD_SIZE: Vector2 = Vector2 { x: 450.0, y: 900.0 };
+
+        let lines = self.message();
+
+        if lines.is_empty() {
+            return canvas;
+        }
+
+        let canvas = canvas.draw(&RectangleGraphic {
+            rectangle: Rectangle {
+                size: PLAYFIELD_SIZE,
+            },
+            position: PLAYFIELD_POSITION,
+            color: Color::BLACK.fade(0.6),
+        });
+
+        lines
+            .iter()
+            .enumerate()
+            .fold(canvas, |canvas, (index, line)| {
+                canvas.draw(&TextGraphic {
+                    text: line,
+                    position: PLAYFIELD_POSITION
+                        + Vector2 {
+                            x: 40.0,
+                            y: PLAYFIELD_SIZE.y / 2.0 + 60.0 * (index as f32),
+                        },
+                    font_size: 40.0,
+                    color: Color::RAY_WHITE,
+                })
+            })
+    }
+}
@@ -0,0 +1,72 @@
+use tetris_core::tetromino::{Tetromino, TetrominoKind};
+
+use super::engine::{
+    shapes::{Rectangle, Vector2},
+    vectors::{Color, Drawable, RectangleGraphic, Renderer},
+};
+
+/// The guideline color of each tetromino.
+pub trait TetrominoColor {
+    fn color(&self) -> Color;
+}
+
+impl TetrominoColor for TetrominoKind {
+    fn color(&self) -> Color {
+        use TetrominoKind::*;
+
+        match self {
+            O => Color::YELLOW,
+            I => Color::SKY_BLUE,
+            T => Color::PURPLE,
+            L => Color::ORANGE,
+            J => Color::BLUE,
+            S => Color::GREEN,
+            Z => Color::RED,
+        }
+    }
+}
+
+/// A tetromino drawn centered on `position`, outside of the matrix.
+pub struct TetrominoGraphic<'a> {
+    pub tetromino: &'a Tetromino,
+    pub position: Vector2,
+    pub cell_size: f32,
+    pub color: Color,
+}
+
+impl<'a, 'b> Drawable<'a> for TetrominoGraphic<'b> {
+    fn draw<C: Renderer>(&self, canvas: C) -> C {
+        let offsets = self
+            .tetromino
+            .kind()
+            .minoes(self.tetromino.rotation_state())
+            .map(|(x, y)| (f32::from(x), f32::from(y)));
+
+        let (x_min, x_max, y_min, y_max) = offsets.iter().fold(
+            (f32::MAX, f32::MIN, f32::MAX, f32::MIN),
+            |(x_min, x_max, y_min, y_max), &(x, y)| {
+                (x_min.min(x), x_max.max(x), y_min.min(y), y_max.max(y))
+            },
+        );
+
+        let x_middle = (x_min + x_max + 1.0) / 2.0;
+        let y_middle = (y_min + y_max + 1.0) / 2.0;
+
+        offsets.iter().fold(canvas, |canvas, &(x, y)| {
+            canvas.draw(&RectangleGraphic {
+                rectangle: Rectangle {
+                    size: Vector2 {
+                        x: self.cell_size,
+                        y: self.cell_size,
+                    },
+                },
+                position: self.position
+                    + Vector2 {
+                        x: self.cell_size * (x - x_middle),
+                        y: self.cell_size * (y_middle - y - 1.0),
+                    },
+                color: self.color,
+            })
+        })
+    }
+}
@@ -0,0 +1,204 @@
+//! Golden image tests of the [Matrix] and [NextQueue] layouts. A known game is rendered with a
+//! headless canvas and sampled at fixed points, and every sample is written as a character so
+//! that the expected frames can be kept right here.
+//!
+//! [Matrix]: tetris_core::matrix::Matrix
+//! [NextQueue]: tetris_core::next_queue::NextQueue
+
+use tetris_core::{Rotation, Step, Tetris, TetrisMove, TetrominoKind};
+use tetris_render::engine::image::Image;
+use tetris_render::engine::vectors::{Background, Canvas, Color, Renderer};
+use tetris_render::TetrominoColor;
+
+const WIDTH: u32 = 1256;
+const HEIGHT: u32 = 1032;
+
+/// A game a few pieces in, with cells in the matrix and a tetromino that has just spawned in
+/// the vanish zone above its ghost.
+fn known_game() -> Tetris {
+    use TetrisMove::*;
+
+    let placements = [
+        vec![Shift(Step::Left), Shift(Step::Left), Shift(Step::Left)],
+        vec![Shift(Step::Right), Shift(Step::Right), Shift(Step::Right)],
+        vec![Rotate(Rotation::Clockwise)],
+        vec![],
+        vec![Shift(Step::Right)],
+    ];
+
+    let mut tetris = (0..150).fold(Tetris::with_seed(3), |tetris, _| tetris.tick(Vec::new()));
+
+    for moves in placements {
+        tetris = (0..10).fold(tetris, |tetris, _| tetris.tick(Vec::new()));
+
+        for single_move in moves {
+            tetris = tetris.tick(vec![single_move]).tick(Vec::new());
+        }
+
+        tetris = tetris.tick(vec![HardDrop]).tick(Vec::new());
+    }
+
+    tetris
+}
+
+fn render(tetris: &Tetris) -> Image {
+    Canvas::headless(WIDTH, HEIGHT)
+        .draw(&Background {
+            color: Color::RAY_WHITE,
+        })
+        .draw(tetris)
+        .into_image()
+        .expect("Should be safe because the canvas is headless")
+}
+
+/// The tetromino letter for a tetromino color, `.` for the gray behind the matrix and the
+/// queues, `-` for the background and `+` for anything else, like a faded ghost cell.
+fn character(color: Color) -> char {
+    use TetrominoKind::*;
+
+    [O, I, T, L, J, S, Z]
+        .into_iter()
+        .find(|kind| kind.color() == color)
+        .map(|kind| format!("{kind:?}").remove(0))
+        .unwrap_or(match color {
+            Color::GRAY => '.',
+            Color::RAY_WHITE => '-',
+            _ => '+',
+        })
+}
+
+/// Samples `image` at every point of the grid, one line of characters per row.
+fn sample(
+    image: &Image,
+    columns: impl Iterator<Item = u32> + Clone,
+    rows: impl Iterator<Item = u32>,
+) -> String {
+    rows.map(|y| {
+        columns
+            .clone()
+            .map(|x| {
+                character(
+                    image
+                        .pixel(x, y)
+                        .expect("Samples should lie inside of the image"),
+                )
+            })
+            .collect::<String>()
+    })
+    .collect::<Vec<String>>()
+    .join("\n")
+}
+
+/// Compares `actual` against `expected`, saving the frame for inspection when they differ.
+fn assert_golden(name: &str, image: &Image, actual: String, expected: &str) {
+    if actual != expected.trim_matches('\n') {
+        let path = format!("{}/{name}.png", env!("CARGO_TARGET_TMPDIR"));
+
+        image
+            .write_png(&path)
+            .expect("Should be able to save the frame to the target directory");
+
+        panic!("{name} doesn't match its golden image, the frame was saved to {path}:\n{actual}");
+    }
+}
+
+#[test]
+fn matrix_layout() {
+    let image = render(&known_game());
+
+    // One sample a little above the bottom of every cell, including the two vanish zone rows
+    // that peek out above the playfield.
+    let columns = (0..10).map(|column| 403 + 45 * column + 22);
+    let rows = (0..22).map(|line| 61 + 45 * line);
+
+    assert_golden(
+        "matrix",
+        &image,
+        sample(&image, columns, rows),
+        "
+----------
+----T-----
+...TTT....
+..........
+..........
+..........
+..........
+..........
+..........
+..........
+..........
+..........
+..........
+..........
+....+.....
+...+++....
+....IIII..
+....OO....
+....OO....
+....L.....
+.SS.L.ZZ..
+SS..LL.ZZ.
+",
+    );
+}
+
+#[test]
+fn next_queue_layout() {
+    let image = render(&known_game());
+
+    let columns = (0..15).map(|column| 883 + 16 * column + 4);
+    let rows = (0..45).map(|row| 21 + 16 * row + 4);
+
+    assert_golden(
+        "next_queue",
+        &image,
+        sample(&image, columns, rows),
+        "
+...............
+...............
+...............
+...............
+...............
+...JJJ.........
+...JJJ.........
+...JJJ.........
+...JJJJJJJJJ...
+...JJJJJJJJJ...
+...JJJJJJJJJ...
+...............
+...............
+...............
+...............
+...............
+...............
+...............
+....IIIIIII....
+....IIIIIII....
+...............
+...............
+...............
+...............
+.....ZZZ.......
+.....ZZZ.......
+.......ZZZ.....
+.......ZZZ.....
+...............
+...............
+...............
+...............
+........LL.....
+........LL.....
+.....LLLLL.....
+...............
+...............
+...............
+...............
+......OOO......
+......OOO......
+......OOO......
+......OOO......
+...............
+...............
+",
+    );
+}
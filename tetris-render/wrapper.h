@@ -0,0 +1 @@
+#include <raylib.h>